@@ -0,0 +1,134 @@
+use std::env;
+use std::path::PathBuf;
+use std::process;
+
+use robotics_lib::runner::{Runnable, Runner};
+use robotics_lib::world::tile::Content;
+use serde::Serialize;
+use worldgen_unwrap::public::WorldgeneratorUnwrap;
+use holy_crab_best_path::{MinerRobot, SilentSound};
+
+// amount of ticks simulated when --ticks is not given
+const DEFAULT_TICKS: usize = 1000;
+
+const USAGE: &str = "Usage: holy_crab_sim <world.bin> [--ticks N] [--config miner.toml]";
+
+/// Command line options of the simulator
+struct Options {
+    world: PathBuf,
+    ticks: usize,
+    config: Option<PathBuf>
+}
+
+/// Machine-readable result of a simulation
+#[derive(Serialize)]
+struct Summary {
+    ticks: usize,
+    coordinates: (usize, usize),
+    rocks: usize,
+    energy: usize,
+    bridges_built: usize,
+    islands_known: usize,
+    termination_reason: String
+}
+
+fn main() {
+    let options = match parse_args(env::args().skip(1).collect()) {
+        Ok(options) => options,
+        Err(msg) => {
+            eprintln!("{}\n{}", msg, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let robot = match &options.config {
+        Some(path) => MinerRobot::from_config_file(path).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            process::exit(2);
+        }),
+        None => MinerRobot::new()
+    };
+    let robot = robot.with_sound(SilentSound);
+    let game_over = robot.game_over.clone();
+    let status = robot.status.clone();
+
+    let mut world_generator = WorldgeneratorUnwrap::init(false, Some(options.world));
+    let mut runner = match Runner::new(Box::new(robot), &mut world_generator) {
+        Ok(runner) => runner,
+        Err(e) => {
+            eprintln!("Cannot create the runner: {:?}", e);
+            process::exit(1);
+        }
+    };
+
+    let mut ticks = 0;
+    let mut termination_reason = String::from("max_ticks");
+    while ticks < options.ticks {
+        if *game_over.lock().unwrap() {
+            break;
+        }
+        if let Err(e) = runner.game_tick() {
+            termination_reason = format!("runner_error: {:?}", e);
+            break;
+        }
+        ticks += 1;
+    }
+
+    let status = status.lock().unwrap().clone();
+    if *game_over.lock().unwrap() {
+        termination_reason = match status.finish_reason {
+            Some(reason) => format!("game_over: {:?}", reason),
+            None => String::from("game_over")
+        };
+    }
+
+    let robot = runner.get_robot();
+    let coordinate = robot.get_coordinate();
+    let summary = Summary {
+        ticks,
+        coordinates: (coordinate.get_row(), coordinate.get_col()),
+        rocks: robot.get_backpack().get_contents().get(&Content::Rock(0)).copied().unwrap_or(0),
+        energy: robot.get_energy().get_energy_level(),
+        bridges_built: status.bridges_built,
+        islands_known: status.islands.len(),
+        termination_reason
+    };
+
+    println!("{}", serde_json::to_string_pretty(&summary).expect("Error while serializing the summary"));
+}
+
+/// Parses the command line arguments
+///
+/// # Arguments
+///
+/// * `args` - the arguments without the program name
+///
+/// # Returns
+///
+/// The parsed Options or a message describing the wrong argument
+fn parse_args(args: Vec<String>) -> Result<Options, String> {
+    let mut world = None;
+    let mut ticks = DEFAULT_TICKS;
+    let mut config = None;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--ticks" => {
+                let value = args.next().ok_or("Missing value for --ticks")?;
+                ticks = value.parse().map_err(|_| format!("Invalid amount of ticks: {}", value))?;
+            },
+            "--config" => {
+                config = Some(PathBuf::from(args.next().ok_or("Missing value for --config")?));
+            },
+            _ if world.is_none() && !arg.starts_with("--") => world = Some(PathBuf::from(arg)),
+            _ => return Err(format!("Unexpected argument: {}", arg))
+        }
+    }
+
+    Ok(Options {
+        world: world.ok_or("Missing world file")?,
+        ticks,
+        config
+    })
+}
@@ -1,10 +1,35 @@
+use std::cell::RefCell;
+use std::collections::{HashSet, VecDeque};
 use std::fmt::Debug;
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 use std::usize;
 
 // modules for MinerRobot
 mod util;
 
+pub use util::config::config::{ConfigError, MinerConfig, MinerConfigBuilder};
+pub use util::error::error::{MinerError, Recovery};
+pub use util::movement::movement::MovementFailure;
+pub use util::state::state::{RobotStatus, StateTransition};
+pub use util::bridge::debug::BridgeStatus;
+pub use util::plan::plan::{BridgePlan, BridgeProgress, PlannedTile};
+pub use util::network::network::BridgeNetwork;
+pub(crate) use util::disjoint_set::disjoint_set::DisjointSet;
+pub use util::cost::cost::{DefaultCostModel, StrictCostModel, TileCostModel};
+pub use util::budget::budget::{RockBudget, RockDeposit};
+pub use util::island_tracker::island_tracker::IslandTracker;
+pub use util::island_info::island_info::IslandInfo;
+pub use util::known_map::known_map::{KnownCell, KnownMap, UnknownPolicy};
+pub use util::graph::graph::{BridgeEdge, IslandGraph, IslandNode};
+pub use util::target::target::{BestValueTarget, ClosestTarget, FrontierTarget, LargestTarget, RichestTarget, TargetCandidate, TargetSelector, TargetStrategy};
+pub use util::generator::generator::{MemoryWorldGenerator, WorldError};
+pub use util::ascii::ascii::{to_ascii, AsciiMapError, AsciiWorld};
+pub use util::scenario::scenario::{Scenario, ScenarioRecord, ScenarioResult};
+pub use util::sound::sound::{DeviceSound, RecordingSound, SilentSound, SoundEvent, SoundSink};
+pub use util::energy::energy::EnergyLedger;
+pub use util::instrumented::instrumented::{EventKind, Instrumented, RunStats};
+
 // robotics lib
 use robotics_lib::event::events::Event;
 use robotics_lib::interface::destroy;
@@ -17,17 +42,17 @@ use robotics_lib::energy::Energy;
 use robotics_lib::world::World;
 
 // tools
-use bessie::bessie::{road_paving_machine, RpmError, State};
+use bessie::bessie::{road_paving_machine, State};
 use bob_lib::tracker::GoalTracker;
 use colored::Colorize;
-use OwnerSheeps_Sound_Tool::functions::weather_sounds::weather_sound;
 use pmp_collect_all::CollectAll;
-use robotics_lib::utils::LibError;
 use rust_and_furious_dynamo::dynamo::Dynamo;
 use sense_and_find_by_rustafariani::Lssf;
 use spyglass::spyglass::Spyglass;
 
 
+// default values of the MinerConfig
+
 // const used to set the goal quantity
 const GOAL_QUANTITY: u32 = 5;
 
@@ -42,10 +67,55 @@ const SCAN_INCREASE: usize = 10;
 // energy threshold, the robot's energy cannot get lower than the threshold
 const MIN_ENERGY: usize = 100;
 
-#[derive(Debug)]
+// range and direction used when collecting rocks around the robot
+const COLLECT_RANGE: usize = 2;
+const COLLECT_DIRECTION: Direction = Direction::Up;
+
+// rocks a new bridge has to save to replace the one the robot is working on
+const RETARGET_MARGIN: usize = 2;
+
+// how many times a failed move gets replanned before giving up
+const MAX_MOVE_RETRIES: usize = 3;
+
+// amount of state transitions kept in the robot's history
+const STATE_HISTORY_SIZE: usize = 64;
+
+// whether the robot builds bridges to every island or only to the one chosen by the target strategy
+const CONNECT_ALL_ISLANDS: bool = false;
+
+// strategy used to choose the island the bridges lead to
+const TARGET_STRATEGY: TargetStrategy = TargetStrategy::Closest;
+
+// how the planners treat the tiles the robot hasn't discovered yet
+const UNKNOWN_POLICY: UnknownPolicy = UnknownPolicy::Pessimistic;
+
+// energy estimated for a destroy() on a rock, the robotics lib charges it from the content properties
+const DESTROY_ENERGY: usize = 3;
+
+// ticks spent on each deposit and on the bridge when estimating a rock budget:
+// the robot moves next to the target on one tick and works on it on the next one
+const TICKS_PER_DEPOSIT: usize = 2;
+const TICKS_PER_BRIDGE: usize = 2;
+
+// amount of coordinates kept in the robot's trail
+const TRAIL_SIZE: usize = 256;
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum RobotState {
+    Exploring,
+    TravellingToTarget,
     CollectingRocks,
-    PavingBridge
+    MovingToBridgeStart,
+    PavingBridge,
+    Recharging,
+    Finished(FinishReason),
+    Stuck
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FinishReason {
+    NothingToCollect,
+    GaveUp(String)
 }
 
 // struct of the robot
@@ -59,7 +129,30 @@ pub struct MinerRobot {
     pub world_scanned: bool,
     pub state: RobotState,
     pub game_over: Arc<Mutex<bool>>,
-    pub coordinates:(usize, usize)
+    pub coordinates:(usize, usize),
+    pub config: MinerConfig,
+    pub movement_failures: VecDeque<MovementFailure>,
+    pub tick: usize,
+    pub state_history: VecDeque<StateTransition>,
+    pub resume_state: Option<RobotState>,
+    pub content_direction: Option<Direction>,
+    pub bridge_target: Option<BridgePlan>,
+    pub locked_bridge: Option<BridgePlan>,
+    pub bridge_progress: Option<BridgeProgress>,
+    pub bridges_built: usize,
+    pub status: Arc<Mutex<RobotStatus>>,
+    pub sound: Box<dyn SoundSink>,
+    pub trail: VecDeque<(usize, usize)>,
+    pub visited: HashSet<(usize, usize)>,
+    pub known_map: Arc<KnownMap>,
+    pub known_map_stale: bool,
+    pub known_map_scans: usize,
+    pub energy_ledger: EnergyLedger,
+    pub cost_model: Box<dyn TileCostModel>,
+    pub target_selector: Box<dyn TargetSelector>,
+    pub rock_budget: Option<RockBudget>,
+    pub island_tracker: RefCell<IslandTracker>,
+    pub known_islands: Vec<IslandInfo>
 }
 
 impl MinerRobot {
@@ -72,18 +165,7 @@ impl MinerRobot {
     ///
     /// A new instance of Self
     pub fn new() -> Self {
-        Self {
-            robot: Robot::new(),
-            name: String::from("The default miner"),
-            goal_tracker: GoalTracker::new(),
-            rocks_collected: 0,
-            scan_distance: SCAN_DISTANCE,
-            lssf: Lssf::new(),
-            world_scanned: false,
-            state: RobotState::CollectingRocks,
-            game_over: Arc::new(Mutex::from(false)),
-            coordinates: (0,0)
-        }
+        Self::with_config(MinerConfig::default())
     }
     /// Creates a new instance of MinerRobot given its name
     ///
@@ -95,19 +177,127 @@ impl MinerRobot {
     ///
     /// A new instance of Self
     pub fn new_name(name: String) -> Self {
+        let mut robot = Self::with_config(MinerConfig::default());
+        robot.name = name;
+        robot
+    }
+    /// Creates a new instance of MinerRobot given its configuration
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - the configuration of the robot
+    ///
+    /// # Returns
+    ///
+    /// A new instance of Self
+    pub fn with_config(config: MinerConfig) -> Self {
+        Self::build(config)
+    }
+    /// Creates a new instance of MinerRobot after validating its configuration
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - the configuration of the robot
+    ///
+    /// # Returns
+    ///
+    /// A new instance of Self, or MinerError::Config if the configuration is not valid
+    pub fn try_with_config(config: MinerConfig) -> Result<Self, MinerError> {
+        config.validate()?;
+        Ok(Self::build(config))
+    }
+    /// Creates a new instance of MinerRobot from a configuration file
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - the path of a `.toml` or `.json` file
+    ///
+    /// # Returns
+    ///
+    /// A new instance of Self, or MinerError::Config if the file cannot be read or is not valid
+    pub fn from_config_file<P: AsRef<Path>>(path: P) -> Result<Self, MinerError> {
+        Ok(Self::build(MinerConfig::from_file(path)?))
+    }
+    /// Creates the robot without checking the configuration
+    fn build(config: MinerConfig) -> Self {
+        let target_selector = config.target_strategy.selector();
         Self {
             robot: Robot::new(),
-            name,
+            name: String::from("The default miner"),
             goal_tracker: GoalTracker::new(),
             rocks_collected: 0,
-            scan_distance: SCAN_DISTANCE,
+            scan_distance: config.scan_distance,
             lssf: Lssf::new(),
             world_scanned: false,
-            state: RobotState::CollectingRocks,
+            state: RobotState::Exploring,
             game_over: Arc::new(Mutex::from(false)),
-            coordinates:(0,0)
+            coordinates: (0,0),
+            config,
+            movement_failures: VecDeque::new(),
+            tick: 0,
+            state_history: VecDeque::new(),
+            resume_state: None,
+            content_direction: None,
+            bridge_target: None,
+            locked_bridge: None,
+            bridge_progress: None,
+            bridges_built: 0,
+            status: Arc::new(Mutex::new(RobotStatus::default())),
+            sound: Box::new(DeviceSound),
+            trail: VecDeque::new(),
+            visited: HashSet::new(),
+            known_map: Arc::default(),
+            known_map_stale: true,
+            known_map_scans: 0,
+            energy_ledger: EnergyLedger::default(),
+            cost_model: Box::new(DefaultCostModel),
+            target_selector,
+            rock_budget: None,
+            island_tracker: RefCell::new(IslandTracker::new()),
+            known_islands: Vec::new()
         }
     }
+    /// Replaces the backend used to play the robot's sounds
+    ///
+    /// # Arguments
+    ///
+    /// * `sound` - the new backend, e.g. SilentSound for headless runs or RecordingSound for tests
+    ///
+    /// # Returns
+    ///
+    /// Self with the new backend
+    pub fn with_sound(mut self, sound: impl SoundSink + 'static) -> Self {
+        self.sound = Box::new(sound);
+        self
+    }
+    /// Replaces the rules used to decide where the robot can walk and how much paving costs
+    ///
+    /// # Arguments
+    ///
+    /// * `cost_model` - the new rules, e.g. StrictCostModel to keep the robot away from walls and teleports
+    ///
+    /// # Returns
+    ///
+    /// Self with the new rules
+    pub fn with_cost_model(mut self, cost_model: impl TileCostModel + 'static) -> Self {
+        self.cost_model = Box::new(cost_model);
+        // the walkable tiles change, so the tracker reads the next map in full
+        self.island_tracker.get_mut().set_map_revision(None);
+        self
+    }
+    /// Replaces the strategy used to choose the island the bridges lead to
+    ///
+    /// # Arguments
+    ///
+    /// * `target_selector` - the new strategy, overriding the one chosen in the configuration
+    ///
+    /// # Returns
+    ///
+    /// Self with the new strategy
+    pub fn with_target_selector(mut self, target_selector: impl TargetSelector + 'static) -> Self {
+        self.target_selector = Box::new(target_selector);
+        self
+    }
 
     /// Utility methods
     ///
@@ -129,7 +319,7 @@ impl MinerRobot {
             self.robot.coordinate.get_row(),
             self.robot.coordinate.get_col(),
             distance,
-            self.get_map(world).len()
+            self.get_map_option(world).len()
         );
         spyglass.set_energy_budget(Some(energy_budget));
         spyglass.set_view_threshold(threshold);
@@ -143,16 +333,10 @@ impl MinerRobot {
     /// * `map` - the map of the discovered world
     /// * `row` - the row coordinate from which we want the cost to be updated
     /// * `col` - the column coordinate from which we want the cost to be updated
-    fn update_lssf_map_and_cost(&mut self, world: &mut World, map: &Vec<Vec<Option<Tile>>>, row: usize, col: usize) {
+    fn update_lssf_map_and_cost(&mut self, map: &Vec<Vec<Option<Tile>>>, row: usize, col: usize) -> Result<(), MinerError> {
         self.lssf.update_map(&map);
-        match self.lssf.update_cost(row,col) {
-            Ok(()) => {
-                //println!("Lssf cost updated successfully")
-            },
-            Err(e) => {
-                self.catch_lib_error(world,e);
-            }
-        }
+        self.lssf.update_cost(row,col)?;
+        Ok(())
     }
     /// Calls the Bessie tool and starts collecting rocks
     ///
@@ -160,15 +344,10 @@ impl MinerRobot {
     ///
     ///  * `world` - the world
     ///  * `direction` - the direction in which the robot starts paving the road
-    fn collect_rocks_inline(&mut self, world: &mut World, direction: Direction) {
-        match road_paving_machine(self,world,direction,State::GetStones) {
-            Ok(()) => {
-                //println!("The Process ended correctly and we made a Road!")
-            }
-            Err(e) => {
-                self.catch_rpm_error(world,e);
-            }
-        }
+    fn collect_rocks_inline(&mut self, world: &mut World, direction: Direction) -> Result<(), MinerError> {
+        self.invalidate_known_map();
+        road_paving_machine(self,world,direction,State::GetStones)?;
+        Ok(())
     }
     /// Collects all the content around the robot
     ///
@@ -177,6 +356,7 @@ impl MinerRobot {
     /// * `world` - the world
     /// * `range` - the range around the robot
     fn collect_all(&mut self, world: &mut World, range: usize) {
+        self.invalidate_known_map();
         CollectAll::collect_all(self,world,range);
     }
     /// Recharges the energy if the energy level goes below the minimum threshold
@@ -185,7 +365,7 @@ impl MinerRobot {
     ///
     /// * `world` - the world
     fn manage_energy(&mut self, world: &mut World) {
-        if self.robot.energy.get_energy_level() < MIN_ENERGY {
+        if self.robot.energy.get_energy_level() < self.config.min_energy {
             self.recharge_energy(world);
         }
     }
@@ -198,11 +378,17 @@ impl MinerRobot {
         let _= destroy(self, world, Direction::Down);
         *self.get_energy_mut() = Dynamo::update_energy();
     }
-    /// Updates self's rock count
-    fn update_rock_count(&mut self) {
-        let contents = self.robot.backpack.get_contents();
-        if let Some(&rocks_amount) = contents.get(&Content::Rock(0)) {
-            self.rocks_collected = rocks_amount;
+    /// Stores a coordinate the robot moved to in the trail and in the visited tiles
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinates` - the robot's new coordinates
+    fn record_move(&mut self, coordinates: (usize, usize)) {
+        self.coordinates = coordinates;
+        self.visited.insert(coordinates);
+        self.trail.push_back(coordinates);
+        if self.trail.len() > TRAIL_SIZE {
+            self.trail.pop_front();
         }
     }
     /// Returns the robot's coordinates
@@ -213,45 +399,78 @@ impl MinerRobot {
     pub fn get_coordinates(&self) -> (usize,usize) {
         (self.robot.coordinate.get_row(),self.robot.coordinate.get_col())
     }
-    /// Catches the LibError
+    /// Applies the recovery policy associated to the error
     ///
     /// # Arguments
     ///
     /// * `world` - the world
-    /// * `error` - the LibError
-    pub fn catch_lib_error(&mut self, world: &mut World, error: LibError) {
-        match error {
-            LibError::NotEnoughEnergy => {
+    /// * `error` - the MinerError returned during the tick
+    pub fn recover(&mut self, world: &mut World, error: MinerError) {
+        println!("{}", error);
+        let next = match error.recovery() {
+            Recovery::Recharge => {
                 println!("Not enough energy, the robot will get its energy refilled");
-                self.recharge_energy(world);
+                RobotState::Recharging
+            },
+            Recovery::Replan => {
+                self.widen_scan(world);
+                RobotState::Exploring
             },
-            LibError::OutOfBounds => println!("Out of bounds"),
-            LibError::NoContent => println!("No content"),
-            LibError::NotEnoughSpace(remainder) => println!("Not enough space: {}", remainder),
-            LibError::CannotDestroy => println!("Cannot destroy"),
-            LibError::NotCraftable => println!("Can't craft this item"),
-            LibError::NoMoreDiscovery => println!("Not enough discoverable tiles"),
-            _ => println!("Generic error: {:?}", error)
+            Recovery::Skip => match error {
+                MinerError::MovementFailed { .. } => RobotState::Stuck,
+                _ => return
+            },
+            Recovery::GiveUp => {
+                self.finish(FinishReason::GaveUp(error.to_string()));
+                return;
+            }
+        };
+        self.enter_state(next);
+    }
+    /// Moves the robot to the given state, remembering where to resume after recharging
+    ///
+    /// # Arguments
+    ///
+    /// * `next` - the next state
+    fn enter_state(&mut self, next: RobotState) {
+        if self.state.is_finished() {
+            return;
+        }
+        if next == RobotState::Recharging && self.state != RobotState::Recharging {
+            self.resume_state = Some(self.state.clone());
+        }
+        if let Err(e) = self.transition_to(next) {
+            println!("{}", e);
         }
     }
-    /// Catches the RpmError for the road paving machine
+    /// Increases the scan distance so that new content and islands can be discovered
     ///
     /// # Arguments
     ///
     /// * `world` - the world
-    /// * `error` - the RpmError
-    pub fn catch_rpm_error(&mut self, world: &mut World, error: RpmError) {
-        match error {
-            RpmError::NotEnoughEnergy => {
-                println!("Not enough energy, the robot will get its energy refilled");
-                self.recharge_energy(world);
-            },
-            RpmError::CannotPlaceHere => println!("Cannot place content on the current tile"),
-            RpmError::OutOfBounds => println!("Out of bounds"),
-            RpmError::NotEnoughMaterial => println!("Not enough material"),
-            RpmError::NoRockHere => println!("No rock here"),
-            RpmError::MustDestroyContentFirst => println!("Must destroy content first"),
-            RpmError::UndefinedError => println!("Undefined error")
+    ///
+    /// # Notes
+    ///
+    /// The way that this is handled is by:
+    /// - increasing the distance
+    /// - setting the scanned value to false in order to call the discover once again
+    /// - getting all the content around the robot
+    /// - trying to collect rocks, or ending the game if the distance got too big
+    fn widen_scan(&mut self, world: &mut World) {
+        // increasing the scan of the spyglass and resetting the world_scanned value in order to scan it again
+        println!("{}", "Increased scan".green());
+        let scan_increase = self.config.scan_increase;
+        self.scan_distance += scan_increase;
+        self.world_scanned = false;
+
+        // setting conditions to use the collect_rocks method and to end the game
+        self.collect_all(world,self.config.collect_range);
+        if self.scan_distance > (scan_increase * 4) {
+            self.finish(FinishReason::NothingToCollect);
+        } else if self.scan_distance > (scan_increase * 3) {
+            if let Err(e) = self.collect_rocks_inline(world,self.config.collect_direction.clone()) {
+                self.recover(world, e);
+            }
         }
     }
     /// Sets the game_over value to true, ending the game
@@ -265,42 +484,62 @@ impl MinerRobot {
 
 impl Runnable for MinerRobot {
     fn process_tick(&mut self, world: &mut World) {
-        weather_sound(world);
+        self.sound.play(SoundEvent::Weather, world);
+        self.tick += 1;
+        self.energy_ledger.start_tick();
+        self.energy_ledger.observe(self.robot.energy.get_energy_level());
 
-        // scanning the area around the robot once
-        self.scan_world(world,self.scan_distance);
+        // the spawn point doesn't generate a Moved event
+        if self.visited.is_empty() {
+            self.record_move(self.get_coordinates());
+        }
 
-        // managing the creation/deletion of goals
-        self.handle_goals();
+        if self.state.is_finished() {
+            return;
+        }
+        // the reads of this tick are served from the cache
+        self.sync_known_map(world);
 
         // self.print_discovered_tiles_content(&world);
         //self.print_discovered_tiles_tile_type(&world);
 
-        // moving and collecting rocks
-        self.move_and_collect_content(world, Content::Rock(1));
-
-        // building a bridge if possible
-        self.pave_bridge(world);
+        // if the robot's energy drops below a certain threshold it recharges before doing anything else
+        if self.robot.energy.get_energy_level() < self.config.min_energy {
+            self.enter_state(RobotState::Recharging);
+        }
 
-        // if the robot's energy drops below a certain threshold it recharges
-        self.manage_energy(world);
+        // running the behaviour of the current state
+        if let Err(e) = self.run_state(world) {
+            self.recover(world, e);
+        }
 
+        self.publish_status();
     }
-    #[allow(dead_code)]
     fn handle_event(&mut self, event: Event) {
         match event {
             Event::Ready => {}
             Event::Terminated => {}
             Event::TimeChanged(_) => {}
             Event::DayChanged(_) => {}
-            Event::EnergyRecharged(_) => {}
-            Event::EnergyConsumed(_) => {}
+            Event::EnergyRecharged(amount) => {
+                self.energy_ledger.recharge(amount);
+                self.energy_ledger.observe(self.robot.energy.get_energy_level());
+            }
+            Event::EnergyConsumed(amount) => {
+                self.energy_ledger.consume(amount);
+                self.energy_ledger.observe(self.robot.energy.get_energy_level());
+            }
             Event::Moved(tile, coordinates) => {
-                let mut coordinate = self.coordinates;
-                coordinate = coordinates;
+                self.record_move(coordinates);
+                self.cache_known_tile(coordinates, tile);
             }
-            Event::TileContentUpdated(_, coordinate) => {}
+            // the event carries the updated tile, so the tile stays known
+            Event::TileContentUpdated(tile, coordinates) => self.cache_known_tile(coordinates, tile),
+            Event::AddedToBackpack(Content::Rock(_), quantity) => self.rocks_collected += quantity,
             Event::AddedToBackpack(_, _) => {}
+            Event::RemovedFromBackpack(Content::Rock(_), quantity) => {
+                self.rocks_collected = self.rocks_collected.saturating_sub(quantity);
+            }
             Event::RemovedFromBackpack(_, _) => {}
         }
     }
@@ -336,15 +575,23 @@ impl Debug for MinerRobot {
         write!(f, "{}",
                format!("{}'s STATS\n\
                - Coordinates: {:?} \n\
-               - Energy: {:?} \n\
+               - Energy: {:?} (consumed {}, recharged {}) \n\
                - Goal_tracker: \n \
                     \t - Goals: {:?} \n \
                     \t - Completed: {} \n\
                - Rocks collected: {:?} \n\
-               - State: {:?} \n",
+               - Bridges built: {} \n\
+               - Islands known: {} \n\
+               - Bridge in progress: {} \n\
+               - {} \n\
+               - State: {:?} (tick {}) \n",
                        self.name, self.get_coordinates(), self.robot.energy.get_energy_level(),
+                       self.energy_ledger.consumed, self.energy_ledger.recharged,
                        self.goal_tracker.get_goals(), self.goal_tracker.get_completed_number(),
-                       self.rocks_collected, self.state
+                       self.rocks_collected, self.bridges_built, self.known_islands.len(),
+                       self.bridge_progress.as_ref().map(|progress| progress.to_string()).unwrap_or(String::from("none")),
+                       self.rock_budget.as_ref().map(|budget| budget.to_string()).unwrap_or(String::from("Rock budget: none")),
+                       self.state, self.tick
                ).green()
         )
     }
@@ -18,17 +18,12 @@ use ggez::graphics::{self, Canvas, Color, DrawParam, Image};
 use ggez::event::{self, EventHandler};
 use ggez::glam::Vec2;
 use rand::Rng;
-use robotics_lib::energy::Energy;
-use robotics_lib::event::events::Event;
 use robotics_lib::runner::backpack;
-use robotics_lib::runner::backpack::BackPack;
 use robotics_lib::runner::{Runnable, Runner};
-use robotics_lib::world::coordinates::Coordinate;
 use robotics_lib::world::tile::{Content, Tile, TileType};
 use robotics_lib::world::world_generator::Generator;
-use robotics_lib::world::World;
 use worldgen_unwrap::public::WorldgeneratorUnwrap;
-use holy_crab_best_path::MinerRobot;
+use holy_crab_best_path::{Instrumented, MinerRobot};
 
 const SCREEN_SIZE: f32 = 1500.;
 const DESIRED_FPS: u32 = 1;
@@ -63,7 +58,7 @@ struct MyGame {
     images: HashMap<TileType, Image>,
     image_robot: Image,
     image_rock: Image,
-    receiver: mpsc::Receiver<(f32,f32,f32,f32)>, // Canale per ricevere le coordinate del robot
+    receiver: mpsc::Receiver<(f32,f32,f32,f32,f32,f32)>, // Canale per ricevere le coordinate del robot
     len_x: f32, 
     len_y: f32,
     offset: (f32,f32),
@@ -73,8 +68,8 @@ struct MyGame {
 impl MyGame {
     fn new(
         ctx: &mut Context,
-        sender: mpsc::Sender<(f32, f32, f32,f32)>, // Aggiungi sender come parametro
-        receiver: mpsc::Receiver<(f32, f32, f32,f32)>, // Aggiungi receiver come parametro
+        sender: mpsc::Sender<(f32, f32, f32,f32,f32,f32)>, // Aggiungi sender come parametro
+        receiver: mpsc::Receiver<(f32, f32, f32,f32,f32,f32)>, // Aggiungi receiver come parametro
     ) -> GameResult<MyGame> {
         let mut hs = HashMap::new();
         hs.insert(TileType::DeepWater, Image::from_path(ctx,"/tiles/Map_tile_37.png")?);
@@ -99,7 +94,10 @@ impl MyGame {
         let len_x = SCREEN_SIZE / map[0].len() as f32;
 
         // Avvio del thread che gestisce la logica del robot
-        let my_robot = MinerRobot::new();
+        let miner = MinerRobot::new();
+        let status = miner.status.clone();
+        let my_robot = Instrumented::new(miner);
+        let stats = my_robot.stats_handle();
 
         thread::spawn(move || {
             // Accedi al MinerRobot all'interno del Mutex
@@ -114,7 +112,10 @@ impl MyGame {
                         let backpack = running.get_robot().get_backpack().get_contents();
                         let number_of_rocks =  *backpack.get(&Content::Rock(0)).unwrap();
                         let level_energy = running.get_robot().get_energy().get_energy_level();
-                        if let Err(e) = sender.send((coord.get_row() as f32, coord.get_col() as f32, level_energy as f32,number_of_rocks as f32)) {
+                        let tick_time = stats.lock().unwrap().last_tick_time.as_secs_f32() * 1000.0;
+                        // -1 when the robot doesn't know yet when it will build the next bridge
+                        let ticks_until_bridge = status.lock().unwrap().ticks_until_bridge.map(|ticks| ticks as f32).unwrap_or(-1.0);
+                        if let Err(e) = sender.send((coord.get_row() as f32, coord.get_col() as f32, level_energy as f32,number_of_rocks as f32,tick_time,ticks_until_bridge)) {
                             println!("Error sending robot coordinates: {:?}", e);
                         }
                         let _ = running.game_tick();
@@ -265,6 +266,18 @@ impl EventHandler for MyGame {
                 canvas.draw(&big_rect_mesh, DrawParam::default());
                 canvas.draw(&red_rect_mesh, DrawParam::default());
 
+                // Tempo impiegato dal robot nell'ultimo tick
+                let text = graphics::Text::new(format!("Tick: {:.1} ms", coord.4));
+                canvas.draw(&text, DrawParam::new().dest(Vec2::new(500.0, 20.0)));
+
+                // Tick stimati prima della costruzione del prossimo ponte
+                let text = if coord.5 < 0.0 {
+                    graphics::Text::new("Bridge in: ?")
+                } else {
+                    graphics::Text::new(format!("Bridge in: {} ticks", coord.5))
+                };
+                canvas.draw(&text, DrawParam::new().dest(Vec2::new(700.0, 20.0)));
+
                 let text = graphics::Text::new(format!("BackPack: "));
                 let text_dest = Vec2::new(1000.0, 20.0);
                 canvas.draw(&text, DrawParam::new().dest(text_dest));
@@ -282,60 +295,3 @@ impl EventHandler for MyGame {
         Ok(())
     }
 }
-
-pub struct BotWrapper{
-    pub bot: MinerRobot
-}
-
-impl BotWrapper{
-    pub(crate) fn new(bot: MinerRobot) ->BotWrapper{
-        BotWrapper{
-            bot
-        }
-    }
-}
-
-impl Runnable for BotWrapper{
-    fn process_tick(&mut self, world: &mut World) {
-        self.bot.process_tick(world)
-    }
-
-    fn handle_event(&mut self, event: Event) {
-        match event {
-            Event::Ready => todo!(),
-            Event::Terminated => todo!(),
-            Event::TimeChanged(_) => todo!(),
-            Event::DayChanged(_) => todo!(),
-            Event::EnergyRecharged(_) => todo!(),
-            Event::EnergyConsumed(_) => todo!(),
-            Event::Moved(_, _) => todo!(),
-            Event::TileContentUpdated(_, _) => todo!(),
-            Event::AddedToBackpack(_, _) => todo!(),
-            Event::RemovedFromBackpack(_, _) => todo!(),
-        }
-    }
-
-    fn get_energy(&self) -> &Energy {
-        self.bot.get_energy()
-    }
-
-    fn get_energy_mut(&mut self) -> &mut Energy {
-        self.bot.get_energy_mut()
-    }
-
-    fn get_coordinate(&self) -> &Coordinate {
-        self.get_coordinate()
-    }
-
-    fn get_coordinate_mut(&mut self) -> &mut Coordinate {
-        self.get_coordinate_mut()
-    }
-
-    fn get_backpack(&self) -> &BackPack {
-        self.get_backpack()
-    }
-
-    fn get_backpack_mut(&mut self) -> &mut BackPack {
-        self.get_backpack_mut()
-    }
-}
\ No newline at end of file
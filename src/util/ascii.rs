@@ -0,0 +1,280 @@
+pub mod ascii {
+    // std
+    use std::fmt::{Display, Formatter};
+    use std::fs;
+    use std::path::Path;
+
+    // MinerRobot
+    use crate::{KnownMap, MemoryWorldGenerator, WorldError};
+
+    // robotics lib
+    use robotics_lib::world::tile::{Content, Tile, TileType};
+
+    // quantity given to the content that is read from a map
+    const DEFAULT_QUANTITY: usize = 1;
+
+    // char that marks the robot's spawn point, the tile below it is Grass
+    const SPAWN: char = '@';
+
+    // char used for tiles without content
+    const NO_CONTENT: char = '+';
+
+    /// Errors that can occur while reading an ASCII map
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum AsciiMapError {
+        Io(String),
+        UnknownTile { line: usize, col: usize, found: char },
+        UnknownContent { line: usize, col: usize, found: char },
+        RaggedRow { line: usize, len: usize, expected: usize },
+        LayerSizeMismatch { tiles: usize, contents: usize },
+        MissingSpawn,
+        MultipleSpawns
+    }
+
+    impl Display for AsciiMapError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            match self {
+                AsciiMapError::Io(msg) => write!(f, "Cannot read the map: {}", msg),
+                AsciiMapError::UnknownTile { line, col, found } => write!(f, "Unknown tile '{}' at line {}, column {}", found, line, col),
+                AsciiMapError::UnknownContent { line, col, found } => write!(f, "Unknown content '{}' at line {}, column {}", found, line, col),
+                AsciiMapError::RaggedRow { line, len, expected } => write!(f, "Line {} has {} chars, expected {}", line, len, expected),
+                AsciiMapError::LayerSizeMismatch { tiles, contents } => write!(f, "The tile layer has {} rows but the content layer has {}", tiles, contents),
+                AsciiMapError::MissingSpawn => write!(f, "The map has no spawn point '{}'", SPAWN),
+                AsciiMapError::MultipleSpawns => write!(f, "The map has more than one spawn point '{}'", SPAWN)
+            }
+        }
+    }
+
+    impl std::error::Error for AsciiMapError {}
+
+    /// A world described by a plain-text map
+    ///
+    /// # Notes
+    ///
+    /// The format is made of:
+    /// - a tile layer, one char per tile type, with `@` marking the spawn point
+    /// - an optional content layer, separated from the tile layer by an empty line, one char per content
+    ///
+    /// Lines starting with `#` are comments. The chars are the same used by the debug printers:
+    /// - tiles: `D` DeepWater, `o` ShallowWater, `G` Grass, `H` Hill, `L` Lava, `M` Mountain, `S` Sand,
+    ///   `N` Snow, `R` Street, `T` Teleport, `W` Wall
+    /// - contents: `+` None, `A` Bank, `I` Bin, `B` Building, `H` Bush, `C` Crate, `O` Coin, `F` Fire, `P` Fish,
+    ///   `G` Garbage, `J` JollyBlock, `M` Market, `R` Rock, `S` Scarecrow, `T` Tree, `W` Water
+    ///
+    /// Content quantities are not part of the format: every content read from a map has quantity 1,
+    /// and the spawn tile is always Grass.
+    #[derive(Debug, Clone)]
+    pub struct AsciiWorld {
+        pub map: Vec<Vec<Tile>>,
+        pub spawn: (usize, usize)
+    }
+
+    impl AsciiWorld {
+        /// Parses an ASCII map
+        ///
+        /// # Arguments
+        ///
+        /// * `text` - the map
+        ///
+        /// # Returns
+        ///
+        /// The parsed world or an AsciiMapError
+        pub fn parse(text: &str) -> Result<Self, AsciiMapError> {
+            // splitting the text in layers, skipping comments and the empty lines around the layers
+            let mut layers: Vec<Vec<(usize, &str)>> = vec![Vec::new()];
+            for (i, line) in text.lines().enumerate() {
+                let line = line.trim_end();
+                if line.starts_with('#') {
+                    continue;
+                }
+                if line.is_empty() {
+                    if !layers.last().unwrap().is_empty() {
+                        layers.push(Vec::new());
+                    }
+                    continue;
+                }
+                layers.last_mut().unwrap().push((i + 1, line));
+            }
+            layers.retain(|layer| !layer.is_empty());
+
+            let tile_layer = layers.first().cloned().unwrap_or_default();
+            let width = tile_layer.first().map(|(_, line)| line.chars().count()).unwrap_or(0);
+
+            // reading the tile layer
+            let mut map = Vec::new();
+            let mut spawn = None;
+            for (row, (line_number, line)) in tile_layer.iter().enumerate() {
+                Self::check_width(*line_number, line, width)?;
+                let mut tiles = Vec::new();
+                for (col, c) in line.chars().enumerate() {
+                    let tile_type = if c == SPAWN {
+                        if spawn.is_some() {
+                            return Err(AsciiMapError::MultipleSpawns);
+                        }
+                        spawn = Some((row, col));
+                        TileType::Grass
+                    } else {
+                        char_to_tile_type(c).ok_or(AsciiMapError::UnknownTile { line: *line_number, col: col + 1, found: c })?
+                    };
+                    tiles.push(Tile { tile_type, content: Content::None, elevation: 0 });
+                }
+                map.push(tiles);
+            }
+
+            // reading the optional content layer
+            if let Some(content_layer) = layers.get(1) {
+                if content_layer.len() != map.len() {
+                    return Err(AsciiMapError::LayerSizeMismatch { tiles: map.len(), contents: content_layer.len() });
+                }
+                for (row, (line_number, line)) in content_layer.iter().enumerate() {
+                    Self::check_width(*line_number, line, width)?;
+                    for (col, c) in line.chars().enumerate() {
+                        map[row][col].content = char_to_content(c).ok_or(AsciiMapError::UnknownContent { line: *line_number, col: col + 1, found: c })?;
+                    }
+                }
+            }
+
+            Ok(Self { map, spawn: spawn.ok_or(AsciiMapError::MissingSpawn)? })
+        }
+        /// Reads and parses an ASCII map file
+        ///
+        /// # Arguments
+        ///
+        /// * `path` - the path of the map
+        pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, AsciiMapError> {
+            let text = fs::read_to_string(path).map_err(|e| AsciiMapError::Io(e.to_string()))?;
+            Self::parse(&text)
+        }
+        /// Writes the world in the ASCII format, the content layer is omitted if every tile is empty
+        pub fn to_ascii(&self) -> String {
+            to_ascii(&self.map, self.spawn)
+        }
+        /// Returns the world as a KnownMap where every tile has been discovered
+        pub fn known_map(&self) -> KnownMap {
+            KnownMap::from(self.map.clone())
+        }
+        /// Converts the world into a generator that can be given to the Runner
+        pub fn into_generator(self) -> Result<MemoryWorldGenerator, WorldError> {
+            MemoryWorldGenerator::new(self.map, self.spawn)
+        }
+        /// Checks that a line has the same width of the map
+        fn check_width(line_number: usize, line: &str, width: usize) -> Result<(), AsciiMapError> {
+            let len = line.chars().count();
+            if len != width {
+                return Err(AsciiMapError::RaggedRow { line: line_number, len, expected: width });
+            }
+            Ok(())
+        }
+    }
+
+    /// Writes a map in the ASCII format
+    ///
+    /// # Arguments
+    ///
+    /// * `map` - the tiles of the world
+    /// * `spawn` - the robot's spawn point
+    ///
+    /// # Returns
+    ///
+    /// The map as a String, the content layer is omitted if every tile is empty
+    pub fn to_ascii(map: &Vec<Vec<Tile>>, spawn: (usize, usize)) -> String {
+        let mut text = String::new();
+        for (i, row) in map.iter().enumerate() {
+            for (j, tile) in row.iter().enumerate() {
+                text.push(if (i, j) == spawn { SPAWN } else { tile_type_to_char(&tile.tile_type) });
+            }
+            text.push('\n');
+        }
+
+        if map.iter().flatten().any(|tile| tile.content != Content::None) {
+            text.push('\n');
+            for row in map {
+                for tile in row {
+                    text.push(content_to_char(&tile.content));
+                }
+                text.push('\n');
+            }
+        }
+        text
+    }
+
+    /// Returns the char associated to the TileType
+    pub fn tile_type_to_char(tile_type: &TileType) -> char {
+        match tile_type {
+            TileType::DeepWater => 'D',
+            TileType::Grass => 'G',
+            TileType::Hill => 'H',
+            TileType::Lava => 'L',
+            TileType::Mountain => 'M',
+            TileType::Sand => 'S',
+            TileType::ShallowWater => 'o',
+            TileType::Snow => 'N',
+            TileType::Street => 'R',
+            TileType::Teleport(_) => 'T',
+            TileType::Wall => 'W'
+        }
+    }
+
+    /// Returns the TileType associated to the char
+    pub fn char_to_tile_type(c: char) -> Option<TileType> {
+        Some(match c {
+            'D' => TileType::DeepWater,
+            'G' => TileType::Grass,
+            'H' => TileType::Hill,
+            'L' => TileType::Lava,
+            'M' => TileType::Mountain,
+            'S' => TileType::Sand,
+            'o' => TileType::ShallowWater,
+            'N' => TileType::Snow,
+            'R' => TileType::Street,
+            'T' => TileType::Teleport(false),
+            'W' => TileType::Wall,
+            _ => return None
+        })
+    }
+
+    /// Returns the char associated to the Content
+    pub fn content_to_char(content: &Content) -> char {
+        match content {
+            Content::Bank(_) => 'A',
+            Content::Bin(_) => 'I',
+            Content::Building => 'B',
+            Content::Bush(_) => 'H',
+            Content::Crate(_) => 'C',
+            Content::Coin(_) => 'O',
+            Content::Fire => 'F',
+            Content::Fish(_) => 'P',
+            Content::Garbage(_) => 'G',
+            Content::JollyBlock(_) => 'J',
+            Content::Market(_) => 'M',
+            Content::Rock(_) => 'R',
+            Content::Scarecrow => 'S',
+            Content::Tree(_) => 'T',
+            Content::Water(_) => 'W',
+            Content::None => NO_CONTENT
+        }
+    }
+
+    /// Returns the Content associated to the char
+    pub fn char_to_content(c: char) -> Option<Content> {
+        Some(match c {
+            'A' => Content::Bank(0..DEFAULT_QUANTITY),
+            'I' => Content::Bin(0..DEFAULT_QUANTITY),
+            'B' => Content::Building,
+            'H' => Content::Bush(DEFAULT_QUANTITY),
+            'C' => Content::Crate(0..DEFAULT_QUANTITY),
+            'O' => Content::Coin(DEFAULT_QUANTITY),
+            'F' => Content::Fire,
+            'P' => Content::Fish(DEFAULT_QUANTITY),
+            'G' => Content::Garbage(DEFAULT_QUANTITY),
+            'J' => Content::JollyBlock(DEFAULT_QUANTITY),
+            'M' => Content::Market(DEFAULT_QUANTITY),
+            'R' => Content::Rock(DEFAULT_QUANTITY),
+            'S' => Content::Scarecrow,
+            'T' => Content::Tree(DEFAULT_QUANTITY),
+            'W' => Content::Water(DEFAULT_QUANTITY),
+            NO_CONTENT | '.' => Content::None,
+            _ => return None
+        })
+    }
+}
@@ -1,177 +1,497 @@
 pub mod debug {
+    // std
+    use std::cmp::Reverse;
+    use std::collections::{BinaryHeap, HashSet};
+
     // MinerRobot
-    use crate::{MinerRobot, RobotState};
+    use crate::{BridgePlan, BridgeProgress, KnownMap, MinerError, MinerRobot, PlannedTile};
 
     // robotics lib
-    use robotics_lib::interface::{go, put};
-    use robotics_lib::interface::Direction;
+    use robotics_lib::interface::put;
+    use robotics_lib::runner::Runnable;
     use robotics_lib::world::tile::{Content, TileType};
     use robotics_lib::world::World;
-    use robotics_lib::world::tile::Tile;
 
-    // tools
-    use OwnerSheeps_Sound_Tool::functions::put_sounds::{play_sound_rock_in_g_h_s_s, play_sound_rock_in_lava, play_sound_rock_in_water};
+    // sounds
+    use crate::util::sound::sound::SoundEvent;
+
+    // energy used to put a single rock, used to estimate the energy of a bridge
+    const PUT_ENERGY_PER_ROCK: usize = 1;
+
+    /// Result of a step towards the construction of a bridge
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum BridgeStatus {
+        /// the collected rocks are not enough to build the bridge
+        NotEnoughRocks,
+        /// the robot moved towards the starting tile, but the target changed
+        MovingToStart,
+        /// the robot is on the starting tile: contains the plan of the bridge
+        Ready(BridgePlan)
+    }
 
     impl MinerRobot {
-        /// Builds the bridge if the collected rocks are enough and if the target doesn't change with time
+        /// Moves the robot towards the starting tile of the locked bridge
         ///
         /// # Arguments
         ///
         /// * `world` - the world
         ///
+        /// # Returns
+        ///
+        /// The BridgeStatus, or a MinerError if there is no bridge to build
+        ///
         /// # Notes
         ///
-        /// The robot performs a certain amount of iterations to make sure that the target is correct:
-        /// - we move the robot to the starting tile
-        /// - once the robot is on the starting tile we calculate the bridge points one more time:
-        ///     - if they change it means that the starting tile is somewhere else, and we repeat the process
-        ///     - if they stay the same we start building the bridge
-        pub fn pave_bridge(&mut self, world: &mut World) {
-            let (mut target_island_coords, mut robot_island_coords) = self.calculate_bridge_points(world);
-
-            // we want to make sure that the target is the right one, so we iterate n amount of times
-            let mut iterations = 0;
-            let max_iterations = 10;
-
-            while iterations < max_iterations {
-                let (robot_row,robot_col) = self.get_coordinates();
-                // checking the collected rock's amount
-                let rocks_to_build_bridge = self.get_paving_cost(&self.get_map(world), robot_island_coords, target_island_coords);
-                if self.rocks_collected < rocks_to_build_bridge {
-                    break;
-                }
-                // if the robot is not on the starting tile to build the bridge, we move it there
-                if (robot_row as i32, robot_col as i32) != robot_island_coords {
-                    self.move_to_coords(world, &self.get_map(world), robot_island_coords);
+        /// If a bridge has been built only in part, the robot goes back to the last tile it reached and resumes it.
+        ///
+        /// Otherwise the robot moves to the starting tile of the locked bridge and checks the lock once more,
+        /// since the tiles discovered on the way could have made the bridge invalid or a much cheaper one available.
+        ///
+        /// In both cases the rocks only need to be enough to pave the next tile.
+        pub fn prepare_bridge(&mut self, world: &mut World) -> Result<BridgeStatus, MinerError> {
+            if let Some(progress) = self.bridge_progress.clone() {
+                if self.rocks_collected < progress.next_tile_rocks() {
+                    return Ok(BridgeStatus::NotEnoughRocks);
                 }
-                let (new_target_island_coords, new_robot_island_coords) = self.calculate_bridge_points(world);
-                if new_target_island_coords == target_island_coords {
-                    self.start_building_bridge(world, target_island_coords);
-                    self.rocks_collected -= rocks_to_build_bridge;
-                    break;
-                } else {
-                    target_island_coords = new_target_island_coords;
-                    robot_island_coords = new_robot_island_coords;
+                let (robot_row,robot_col) = self.get_coordinates();
+                if (robot_row as i32, robot_col as i32) != progress.resume_tile() {
+                    self.move_to_coords(world, progress.resume_tile())?;
                 }
-                iterations += 1;
+                return Ok(BridgeStatus::Ready(progress.plan));
+            }
+
+            let plan = self.lock_bridge_plan(world)?;
+            let (robot_row,robot_col) = self.get_coordinates();
+
+            // checking the collected rock's amount
+            if self.rocks_collected < Self::first_tile_rocks(&plan) {
+                return Ok(BridgeStatus::NotEnoughRocks);
+            }
+            // if the robot is not on the starting tile to build the bridge, we move it there
+            if (robot_row as i32, robot_col as i32) != plan.start {
+                self.move_to_coords(world, plan.start)?;
             }
-            // the game ends if there have been too many iterations without finding the correct tile
-            if iterations >= max_iterations {
-                println!("The target keeps on changing {}", max_iterations);
-                self.game_is_over();
-                println!("{:?}", self);
+            let plan = self.lock_bridge_plan(world)?;
+            let (robot_row,robot_col) = self.get_coordinates();
+            if plan.start == (robot_row as i32, robot_col as i32) {
+                return Ok(BridgeStatus::Ready(plan));
             }
+            // the lock moved to another bridge
+            Ok(BridgeStatus::MovingToStart)
         }
-        /// Starts building the bridge and sets the robot's state to PavingBridge
+        /// Checks whether the collected rocks are enough to pave the next tile of a bridge to the target island
         ///
         /// # Arguments
         ///
         /// * `world` - the world
-        /// * `(target_island_row,target_island_col)` - the target island's coordinates
-        fn start_building_bridge(&mut self, world: &mut World, (target_island_row,target_island_col): (i32, i32)) {
-            self.state = RobotState::PavingBridge;
-            self.build_along_row_and_col(world, target_island_row, target_island_col);
+        ///
+        /// # Returns
+        ///
+        /// A bool stating whether the bridge can be worked on or not
+        pub fn can_afford_bridge(&mut self, world: &World) -> bool {
+            if let Some(progress) = &self.bridge_progress {
+                return self.rocks_collected >= progress.next_tile_rocks();
+            }
+            match self.lock_bridge_plan(world) {
+                Ok(plan) => self.rocks_collected >= Self::first_tile_rocks(&plan),
+                Err(_) => false
+            }
         }
-        /// Calculates the coordinates that will be connected by the bridge
+        /// Builds the bridge described by the plan, as far as the collected rocks allow
         ///
         /// # Arguments
         ///
         /// * `world` - the world
+        /// * `plan` - the plan returned by plan_bridge()
         ///
-        /// # Returns
+        /// # Notes
         ///
-        /// A tuple of coordinates indicating the two coordinates that will be at the start and at the end of the bridge
-        fn calculate_bridge_points(&mut self, world: &World) -> ((i32,i32),(i32,i32)) {
-            let mut islands = self.get_islands(&self.get_map(world));
-            let discovered_tiles = self.get_map(world);
-
-            // getting both the robot's island and the target island
-            let robot_island = self.get_robot_island(&islands).unwrap_or_else(|| vec![]);
-            let target_island = self.get_closest_island_to_robot(&mut islands).unwrap_or_else(|| vec![(0, 0)]);
+        /// The robot moves to the plan's starting tile first, or to the last tile it reached if the plan was started before.
+        /// If the bridge isn't finished, because the rocks ran out or a move failed,
+        /// the progress is stored in `bridge_progress` so that the construction can resume later
+        pub fn execute_bridge(&mut self, world: &mut World, plan: &BridgePlan) -> Result<(), MinerError> {
+            let start_index = match &self.bridge_progress {
+                Some(progress) if &progress.plan == plan => progress.reached,
+                _ => 0
+            };
+            let (robot_row, robot_col) = self.get_coordinates();
+            if (robot_row as i32, robot_col as i32) != plan.path[start_index] {
+                self.move_to_coords(world, plan.path[start_index])?;
+            }
+            let result = self.build_along_path(world, &plan.path[start_index..]);
 
-            self.get_closest_points(&discovered_tiles, robot_island, target_island).unwrap_or_else(|| ((0, 0), (0, 0)))
+            // storing how far the robot got
+            let (robot_row, robot_col) = self.get_coordinates();
+            let reached = plan.path.iter()
+                .position(|tile| *tile == (robot_row as i32, robot_col as i32))
+                .unwrap_or(start_index);
+            if reached + 1 >= plan.path.len() {
+                self.bridge_progress = None;
+                if let Err(e) = self.verify_bridge(world, plan) {
+                    // resuming from the tile before the first one that isn't paved
+                    if let MinerError::BridgeVerificationFailed { unpaved, .. } = &e {
+                        if let Some(index) = plan.path.iter().position(|tile| unpaved.contains(tile)) {
+                            self.bridge_progress = Some(BridgeProgress { plan: plan.clone(), reached: index.saturating_sub(1) });
+                        }
+                    }
+                    return Err(e);
+                }
+                self.locked_bridge = None;
+                self.bridges_built += 1;
+            } else {
+                self.bridge_progress = Some(BridgeProgress { plan: plan.clone(), reached });
+            }
+            result
         }
-        /// Calls the method to build a bridge on both rows and columns
+        /// Checks that a bridge has really been built and syncs the rock count with the backpack
         ///
         /// # Arguments
         ///
         /// * `world` - the world
+        /// * `plan` - the plan of the bridge
+        ///
+        /// # Returns
+        ///
+        /// MinerError::BridgeVerificationFailed if the bridge is not complete
+        pub fn verify_bridge(&mut self, world: &World, plan: &BridgePlan) -> Result<(), MinerError> {
+            // the rocks used by the bridge have been removed from the backpack
+            let rocks = self.get_backpack().get_contents().get(&Content::Rock(0)).copied().unwrap_or(0);
+            self.rocks_collected = rocks;
+            self.check_bridge(&self.get_known_map(world), plan)
+        }
+        /// Checks on the known map that a bridge connects its two islands
+        ///
+        /// # Arguments
+        ///
         /// * `map` - the known world
-        /// * `row` - the target's row
-        /// * `col` - the target's column
+        /// * `plan` - the plan of the bridge
         ///
-        /// # Notes:
+        /// # Returns
         ///
-        /// Given the distance between the robot's row coordinate and the target's it calls the build_to_direction() method.
-        /// The same goes for the column.
-        fn build_along_row_and_col(&mut self, world: &mut World, row: i32, col: i32) {
-            let (robot_row,robot_col) = self.get_coordinates();
-            let row_distance = robot_row as i32 - row;
-            let col_distance = robot_col as i32 - col;
-
-            // building following rows
-            if row_distance < 0 {
-                self.build_to_direction(world,row_distance,&Direction::Down);
-            } else if row_distance > 0 {
-                self.build_to_direction(world,row_distance,&Direction::Up);
-            }
-            // building following columns
-            if col_distance < 0 {
-                self.build_to_direction(world,col_distance,&Direction::Right);
-            } else if col_distance > 0 {
-                self.build_to_direction(world,col_distance,&Direction::Left);
-            }
-            if row_distance == 0 && col_distance == 0 {
-                println!("Cannot build since the robot is already on the target tile");
+        /// MinerError::BridgeVerificationFailed listing the planned tiles that are not walkable,
+        /// and whether the start and the end of the bridge are on the same island
+        pub fn check_bridge(&self, map: &KnownMap, plan: &BridgePlan) -> Result<(), MinerError> {
+            let unpaved: Vec<(i32, i32)> = plan.tiles.iter()
+                .map(|tile| tile.coordinates)
+                .filter(|&(row, col)| !self.is_walkable_at(map, row, col))
+                .collect();
+            let islands_merged = self.sync_island_tracker(map).same_island(plan.start, plan.end);
+
+            if unpaved.is_empty() && islands_merged {
+                Ok(())
+            } else {
+                Err(MinerError::BridgeVerificationFailed { unpaved, islands_merged })
             }
         }
-        /// Builds a bridge given a direction and a distance
+        /// Returns the bridge the robot is working on, locking a new one if there is none
         ///
         /// # Arguments
         ///
         /// * `world` - the world
+        ///
+        /// # Returns
+        ///
+        /// The locked BridgePlan, or a MinerError if there is no bridge to build
+        ///
+        /// # Notes
+        ///
+        /// The locked bridge is kept until it's built, so that the target doesn't change every time the robot moves.
+        /// It's replaced only if it isn't valid anymore on the known map,
+        /// or if a new bridge needs more than `retarget_margin` rocks less
+        pub fn lock_bridge_plan(&mut self, world: &World) -> Result<BridgePlan, MinerError> {
+            let map = self.get_known_map(world);
+            self.lock_bridge_plan_on(&map)
+        }
+        /// Same as lock_bridge_plan(), but on a known map instead of the world
+        ///
+        /// # Arguments
+        ///
         /// * `map` - the known world
-        /// * `distance` - the amount of blocks that are getting paved
-        /// * `direction` - the direction that the robot will pave on
-        fn build_to_direction(&mut self, world: &mut World, distance: i32, direction: &Direction) {
-            let mut distance_left = distance.abs();
-
-            // iterating through all the tiles that need to connect the robot to the target except for the last one which is the target tile
-            while distance_left > 1 {
-                let map = self.get_map(world);
-                let (mut row,mut col) = self.get_coordinates();
-
-                // calculating the offset in order to find the next tile's coordinates
-                let (offset_row,offset_col) = self.direction_to_offset(&direction);
-                row = ((row as i32) + offset_row) as usize;
-                col = ((col as i32) + offset_col) as usize;
-
-                // calculating the amount of rocks needed to build the bridge
-                let quantity = self.get_tile_cost(&map[row][col].tile_type);
-
-                // calling put to pave the bridge if the coordinates are within bounds and the tile is not walkable
-                let error = if self.is_in_bounds(&map,row as i32,col as i32) && !self.is_walkable(&map[row][col].tile_type) {
-                    put(self, world, Content::Rock(0), quantity, direction.clone())
-                } else {
-                    Ok(0)
-                };
-                let _ = match error {
-                    Ok(_) => {
-                        self.play_sound_paving(&map[row][col].tile_type);
-                        self.manage_energy(world);
-                        // in case of error a message is returned
-                        let msg = format!("Failed to move {:?}", direction);
-                        go(self,world,direction.clone()).expect(msg.as_str());
-                        Ok(())
-                    },
-                    Err(e) => {
-                        self.catch_lib_error(world, e);
-                        Err(())
+        ///
+        /// # Returns
+        ///
+        /// The locked BridgePlan, or a MinerError if there is no bridge to build
+        pub fn lock_bridge_plan_on(&mut self, map: &KnownMap) -> Result<BridgePlan, MinerError> {
+            let candidate = self.calculate_bridge_plan(map);
+            if let Some(locked) = self.locked_bridge.clone() {
+                if self.is_plan_valid(map, &locked) {
+                    // the locked bridge is replaced only by a much cheaper one
+                    let cheaper = matches!(&candidate, Ok(plan) if plan.total_rocks + self.config.retarget_margin < locked.total_rocks);
+                    if !cheaper {
+                        return Ok(locked);
                     }
+                }
+            }
+            self.locked_bridge = candidate.as_ref().ok().cloned();
+            candidate
+        }
+        /// Checks that a plan can still be built on the known map
+        ///
+        /// # Arguments
+        ///
+        /// * `map` - the known world
+        /// * `plan` - the plan of the bridge
+        ///
+        /// # Returns
+        ///
+        /// A bool stating whether the bridge starts on the robot's island, ends on another island
+        /// and only crosses tiles that are walkable or can be paved
+        pub fn is_plan_valid(&self, map: &KnownMap, plan: &BridgePlan) -> bool {
+            let islands = self.get_islands(map);
+            let robot_island = match self.get_robot_island(&islands) {
+                Some(island) => island,
+                None => return false
+            };
+            let crossable = plan.path.iter().all(|&(row, col)| self.is_walkable_at(map, row, col) || self.is_pavable_at(map, row, col));
+            let (end_row, end_col) = plan.end;
+
+            crossable
+                && robot_island.contains(&plan.start)
+                && !robot_island.contains(&plan.end)
+                && self.is_walkable_at(map, end_row, end_col)
+        }
+        /// Returns the rocks needed to pave the first tile of a plan
+        fn first_tile_rocks(plan: &BridgePlan) -> usize {
+            plan.tiles.first().map(|tile| tile.rocks).unwrap_or(0)
+        }
+        /// Plans the cheapest bridge between the robot's island and the island chosen by the target selector, without touching the world
+        ///
+        /// # Arguments
+        ///
+        /// * `map` - the known world
+        ///
+        /// # Returns
+        ///
+        /// The BridgePlan, or None if the robot's island cannot be found or the selector has no island to choose
+        ///
+        /// # Notes
+        ///
+        /// The selector comes from `target_strategy` in the MinerConfig, or from with_target_selector()
+        pub fn plan_bridge(&self, map: &KnownMap) -> Option<BridgePlan> {
+            let islands = self.get_island_infos(map);
+
+            // getting the robot's island, then letting the selector choose among the islands that can be reached
+            let source_id = self.get_robot_island_info(&islands)?.index;
+            let candidates = self.get_target_candidates(map, &islands, source_id);
+            let target = self.target_selector.select(&candidates)?;
+
+            Some(self.build_plan(map, target.path.clone(), target.bridge_rocks, source_id, target.island))
+        }
+        /// Creates the BridgePlan of a path
+        ///
+        /// # Arguments
+        ///
+        /// * `map` - the known world
+        /// * `path` - the tiles of the bridge, from start to end
+        /// * `total_rocks` - the rocks needed to pave the path
+        /// * `source_island` - the index of the island where the bridge starts
+        /// * `target_island` - the index of the target island
+        pub(crate) fn build_plan(&self, map: &KnownMap, path: Vec<(i32, i32)>, total_rocks: usize, source_island: usize, target_island: usize) -> BridgePlan {
+            let mut tiles = Vec::new();
+            let mut estimated_energy = 0;
+            for &(row, col) in path.iter().skip(1) {
+                let tile_type = match map.planning_type(row, col) {
+                    Some(tile_type) => tile_type,
+                    None => continue
                 };
+                if self.is_walkable(&tile_type) {
+                    estimated_energy += self.get_walk_energy(&tile_type);
+                } else {
+                    // paved tiles become streets
+                    let rocks = self.get_tile_cost(&tile_type);
+                    estimated_energy += rocks * PUT_ENERGY_PER_ROCK + self.get_walk_energy(&TileType::Street);
+                    tiles.push(PlannedTile { coordinates: (row, col), tile_type, rocks });
+                }
+            }
+            BridgePlan {
+                start: path[0],
+                end: *path.last().unwrap_or(&path[0]),
+                path,
+                tiles,
+                total_rocks,
+                estimated_energy,
+                source_island,
+                target_island
+            }
+        }
+        /// Plans the bridge on the robot's known map
+        ///
+        /// # Arguments
+        ///
+        /// * `discovered_tiles` - the known world
+        ///
+        /// # Returns
+        ///
+        /// The BridgePlan, or a MinerError if one of the two islands cannot be found or connected
+        ///
+        /// # Notes
+        ///
+        /// When `connect_all_islands` is set, the bridge is the next one of the network that connects every island
+        pub(crate) fn calculate_bridge_plan(&self, discovered_tiles: &KnownMap) -> Result<BridgePlan, MinerError> {
+            let islands = self.get_islands(discovered_tiles);
+            let robot_island = self.get_robot_island(&islands).ok_or(MinerError::NoRobotIsland)?;
+
+            let plan = if self.config.connect_all_islands {
+                let robot_island_id = islands.iter().position(|island| island == robot_island).ok_or(MinerError::NoRobotIsland)?;
+                self.plan_bridge_network(discovered_tiles).schedule(robot_island_id).into_iter().next()
+            } else {
+                self.plan_bridge(discovered_tiles)
+            };
+            plan.ok_or(MinerError::NoTargetIsland)
+        }
+        /// Finds the bridge that needs the fewest rocks to connect two islands
+        ///
+        /// # Arguments
+        ///
+        /// * `map` - the known world
+        /// * `robot_island` - the island where the bridge starts
+        /// * `target_island` - the island where the bridge ends
+        ///
+        /// # Returns
+        ///
+        /// An option of tuple:
+        /// - the first element is the path, from a tile of the robot's island to a tile of the target island
+        /// - the second element is the amount of rocks needed to pave it
+        pub fn get_cheapest_bridge(&self, map: &KnownMap, robot_island: &[(i32, i32)], target_island: &[(i32, i32)]) -> Option<(Vec<(i32, i32)>, usize)> {
+            if map.rows() == 0 {
+                return None;
+            }
+            let mut owners = vec![vec![None; map.cols()]; map.rows()];
+            for &(row, col) in robot_island {
+                owners[row as usize][col as usize] = Some(0);
+            }
+            for &(row, col) in target_island {
+                owners[row as usize][col as usize] = Some(1);
+            }
+            self.get_cheapest_bridges(map, robot_island, &owners)
+                .into_iter()
+                .find(|(island, _, _)| *island == 1)
+                .map(|(_, path, cost)| (path, cost))
+        }
+        /// Finds the bridges that need the fewest rocks to connect an island to every other island
+        ///
+        /// # Arguments
+        ///
+        /// * `map` - the known world
+        /// * `source` - the island where the bridges start
+        /// * `owners` - the index of the island that owns each tile, None for the tiles that don't belong to an island
+        ///
+        /// # Returns
+        ///
+        /// A vector of tuples (island index, path, rocks), the path goes from a tile of the source to a tile of the island
+        ///
+        /// # Notes
+        ///
+        /// The Dijkstra algorithm is run from every tile of the source at once:
+        /// - entering a walkable tile costs nothing
+        /// - entering a non walkable tile costs the rocks needed to pave it, tiles that cannot be paved are skipped
+        /// - the unknown tiles are read with the map's UnknownPolicy, the excluded ones are skipped
+        /// - the search doesn't continue past the tiles of another island, so every bridge connects exactly two islands
+        ///
+        /// When two paths need the same rocks, the shortest one is chosen
+        pub(crate) fn get_cheapest_bridges(&self, map: &KnownMap, source: &[(i32, i32)], owners: &[Vec<Option<usize>>]) -> Vec<(usize, Vec<(i32, i32)>, usize)> {
+            let mut bridges = Vec::new();
+            let (rows, cols) = (map.rows(), map.cols());
+            if rows == 0 {
+                return bridges;
+            }
+            let source_island = source.first().and_then(|&(row, col)| owners[row as usize][col as usize]);
+            let mut remaining: HashSet<usize> = owners.iter().flatten().flatten().copied().filter(|island| Some(*island) != source_island).collect();
 
-                distance_left -= 1;
+            // every node is (cost, steps), so that ties are broken by the path's length
+            let mut best = vec![vec![(usize::MAX, usize::MAX); cols]; rows];
+            let mut previous: Vec<Vec<Option<(i32, i32)>>> = vec![vec![None; cols]; rows];
+            let mut queue = BinaryHeap::new();
+            for &(row, col) in source {
+                if map.in_bounds(row, col) {
+                    best[row as usize][col as usize] = (0, 0);
+                    queue.push(Reverse((0, 0, (row, col))));
+                }
             }
+
+            while let Some(Reverse((cost, steps, (row, col)))) = queue.pop() {
+                if remaining.is_empty() {
+                    break;
+                }
+                if (cost, steps) > best[row as usize][col as usize] {
+                    continue;
+                }
+                let owner = owners[row as usize][col as usize];
+                if owner.is_some() && owner != source_island {
+                    if remaining.remove(&owner.unwrap()) {
+                        // rebuilding the path backwards from the reached tile
+                        let mut path = vec![(row, col)];
+                        while let Some(tile) = previous[path[path.len() - 1].0 as usize][path[path.len() - 1].1 as usize] {
+                            path.push(tile);
+                        }
+                        path.reverse();
+                        bridges.push((owner.unwrap(), path, cost));
+                    }
+                    continue;
+                }
+                for (offset_row, offset_col) in [(0, 1), (0, -1), (1, 0), (-1, 0)] {
+                    let (next_row, next_col) = (row + offset_row, col + offset_col);
+                    let tile_type = match map.planning_type(next_row, next_col) {
+                        Some(tile_type) => tile_type,
+                        None => continue
+                    };
+                    let tile_cost = if self.is_walkable(&tile_type) {
+                        0
+                    } else if self.is_pavable(&tile_type) {
+                        self.get_tile_cost(&tile_type)
+                    } else {
+                        continue;
+                    };
+                    let next = (cost + tile_cost, steps + 1);
+                    if next < best[next_row as usize][next_col as usize] {
+                        best[next_row as usize][next_col as usize] = next;
+                        previous[next_row as usize][next_col as usize] = Some((row, col));
+                        queue.push(Reverse((next.0, next.1, (next_row, next_col))));
+                    }
+                }
+            }
+            bridges
+        }
+        /// Builds a bridge along the given path
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        /// * `path` - the tiles to follow, starting from the tile where the robot stands
+        ///
+        /// # Notes
+        ///
+        /// For every tile of the path the robot paves it if it isn't walkable, and then moves on it.
+        /// The robot stops before the first tile it doesn't have enough rocks for
+        fn build_along_path(&mut self, world: &mut World, path: &[(i32, i32)]) -> Result<(), MinerError> {
+            if path.len() < 2 {
+                println!("Cannot build since the robot is already on the target tile");
+                return Ok(());
+            }
+            for &(row, col) in &path[1..] {
+                let (robot_row, robot_col) = self.get_coordinates();
+
+                // the path is made of adjacent tiles, so the offset gives the direction of the next tile
+                let direction = self.offset_to_direction((row - robot_row as i32, col - robot_col as i32))
+                    .ok_or(MinerError::MovementFailed { target: (row as usize, col as usize), attempts: 0 })?;
+                // the tiles around the robot are always discovered, an unknown one means the path went into the fog
+                let tile_type = self.get_known_map(world).tile(row, col)
+                    .map(|tile| tile.tile_type.clone())
+                    .ok_or(MinerError::MovementFailed { target: (row as usize, col as usize), attempts: 0 })?;
+
+                // calling put to pave the bridge if the tile is not walkable
+                if !self.is_walkable(&tile_type) {
+                    let quantity = self.get_tile_cost(&tile_type);
+                    if self.rocks_collected < quantity {
+                        return Ok(());
+                    }
+                    // the paved tile reaches the cache and the island tracker through the TileContentUpdated event
+                    put(self, world, Content::Rock(0), quantity, direction.clone())?;
+                    self.play_sound_paving(world, &tile_type);
+                }
+                self.step_with_retries(world, &direction)?;
+            }
+            Ok(())
         }
         /// Calculates the total cost of building a bridge from the robot's coordinates to the given ones
         ///
@@ -184,7 +504,11 @@ pub mod debug {
         /// # Returns
         ///
         /// The cost of building a bridge from the robot's coordinates to the target's
-        pub fn get_paving_cost(&self, map: &Vec<Vec<Tile>>, (robot_row,robot_col): (i32,i32), (island_row, island_col): (i32, i32)) -> usize {
+        ///
+        /// # Notes
+        ///
+        /// The tiles excluded by the map's UnknownPolicy don't add anything to the cost
+        pub fn get_paving_cost(&self, map: &KnownMap, (robot_row,robot_col): (i32,i32), (island_row, island_col): (i32, i32)) -> usize {
             // initializing the total cost variable and the tmp_cost (indicates the last evaluated cost)
             let mut cost = 0;
             let mut curr_cost = 0;
@@ -192,7 +516,7 @@ pub mod debug {
             let (mut curr_row, mut curr_col) = (robot_row,robot_col);
 
             // loop through all the coordinates that separate the current coordinates to the target's
-            // since the robot builds first along rows and then along columns, we start calculating the rows first
+            // following first the rows and then the columns
             while curr_row != island_row {
                 let next_row = if (curr_row) < island_row {
                     curr_row + 1
@@ -201,7 +525,7 @@ pub mod debug {
                 } else {
                     curr_row
                 };
-                curr_cost = self.get_tile_cost(&map[next_row as usize][curr_col as usize].tile_type);
+                curr_cost = map.planning_type(next_row, curr_col).map_or(0, |tile_type| self.get_tile_cost(&tile_type));
                 cost += curr_cost;
                 curr_row = next_row;
             }
@@ -213,7 +537,7 @@ pub mod debug {
                 } else {
                     curr_col
                 };
-                curr_cost = self.get_tile_cost(&map[curr_row as usize][next_col as usize].tile_type);
+                curr_cost = map.planning_type(curr_row, next_col).map_or(0, |tile_type| self.get_tile_cost(&tile_type));
                 cost += curr_cost;
                 curr_col = next_col;
             }
@@ -221,16 +545,29 @@ pub mod debug {
             // and the robot doesn't need to build a bridge there
             cost-curr_cost
         }
-        /// Calls the sound tool based on the tile_type
-        fn play_sound_paving(&self, tile_type: &TileType) {
-            match tile_type {
-                TileType::DeepWater => play_sound_rock_in_water(),
-                TileType::ShallowWater => play_sound_rock_in_water(),
-                TileType::Lava => play_sound_rock_in_lava(),
-                _ => play_sound_rock_in_g_h_s_s(),
-            }
+        /// Plays the paving sound based on the tile_type
+        fn play_sound_paving(&mut self, world: &World, tile_type: &TileType) {
+            let event = match tile_type {
+                TileType::DeepWater => SoundEvent::RockInWater,
+                TileType::ShallowWater => SoundEvent::RockInWater,
+                TileType::Lava => SoundEvent::RockInLava,
+                _ => SoundEvent::RockInGrassHillSandSnow,
+            };
+            self.sound.play(event, world);
         }
-        /// Returns the cost of paving a certain tile
+        /// Returns the energy used to walk on a certain tile, following the robot's TileCostModel
+        ///
+        /// # Arguments
+        ///
+        /// * `tile_type` - the TileType that we are looking at
+        ///
+        /// # Returns
+        ///
+        /// The base cost of moving on a tile with the given TileType, without elevation and weather
+        fn get_walk_energy(&self, tile_type: &TileType) -> usize {
+            self.cost_model.walk_energy(tile_type)
+        }
+        /// Returns the cost of paving a certain tile, following the robot's TileCostModel
         ///
         /// # Arguments
         ///
@@ -240,13 +577,15 @@ pub mod debug {
         ///
         /// The cost of paving a tile with the given TileType
         fn get_tile_cost(&self, tile_type: &TileType) -> usize {
-            match tile_type {
-                TileType::DeepWater => 3,
-                TileType::Lava => 3,
-                TileType::ShallowWater => 2,
-                TileType::Mountain => 0,
-                _ => 1
-            }
+            self.cost_model.paving_cost(tile_type)
+        }
+        /// Checks if a non walkable tile can be paved, following the robot's TileCostModel
+        ///
+        /// # Arguments
+        ///
+        /// * `tile_type` - the TileType that we are looking at
+        pub fn is_pavable(&self, tile_type: &TileType) -> bool {
+            self.cost_model.is_pavable(tile_type)
         }
     }
 }
\ No newline at end of file
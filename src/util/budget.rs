@@ -0,0 +1,181 @@
+pub mod budget {
+    // std
+    use std::fmt::{Display, Formatter};
+
+    // MinerRobot
+    use crate::{MinerConfig, MinerError, MinerRobot};
+
+    // robotics lib
+    use robotics_lib::world::tile::Content;
+    use robotics_lib::world::World;
+
+    /// A tile with rocks that the robot knows how to reach
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct RockDeposit {
+        pub coordinates: (usize, usize),
+        /// rocks lying on the tile
+        pub rocks: usize,
+        /// energy needed to reach the tile from the robot's position
+        pub path_cost: usize
+    }
+
+    impl RockDeposit {
+        /// Returns the energy needed to reach the tile and collect its rocks
+        ///
+        /// # Arguments
+        ///
+        /// * `destroy_energy` - the energy used to collect the rocks, see MinerConfig
+        pub fn energy(&self, destroy_energy: usize) -> usize {
+            self.path_cost + destroy_energy
+        }
+    }
+
+    /// The deposits the robot has to harvest before it can build the next bridge
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct RockBudget {
+        /// rocks needed to finish the bridge
+        pub needed: usize,
+        /// rocks the robot already has
+        pub available: usize,
+        /// the cheapest deposits that cover the missing rocks, from the closest to the farthest
+        pub deposits: Vec<RockDeposit>,
+        /// energy needed to harvest the chosen deposits
+        pub harvest_energy: usize,
+        /// energy needed to build the bridge
+        pub bridge_energy: usize,
+        /// rocks still missing after harvesting every known deposit
+        pub shortfall: usize,
+        /// ticks before the robot starts paving, None if the known deposits are not enough
+        pub ticks_until_bridge: Option<usize>
+    }
+
+    impl RockBudget {
+        /// Chooses the deposits to harvest in order to afford a bridge
+        ///
+        /// # Arguments
+        ///
+        /// * `needed` - the rocks needed to finish the bridge
+        /// * `available` - the rocks the robot already has
+        /// * `deposits` - the deposits the robot can reach
+        /// * `bridge_energy` - the energy needed to build the bridge
+        /// * `config` - the configuration that contains the energy and ticks estimates
+        ///
+        /// # Returns
+        ///
+        /// The RockBudget, with every deposit and a shortfall if the deposits can't cover the missing rocks
+        ///
+        /// # Notes
+        ///
+        /// The deposits are chosen with a 0/1 knapsack that covers the missing rocks with the least energy.
+        /// The path costs are measured from the robot's position, so the energy is an estimate
+        pub fn plan(needed: usize, available: usize, deposits: Vec<RockDeposit>, bridge_energy: usize, config: &MinerConfig) -> RockBudget {
+            let missing = needed.saturating_sub(available);
+
+            // cheapest[r] = energy and deposits needed to gather at least r rocks
+            let mut cheapest: Vec<Option<(usize, Vec<usize>)>> = vec![None; missing + 1];
+            cheapest[0] = Some((0, Vec::new()));
+            for (index, deposit) in deposits.iter().enumerate() {
+                if deposit.rocks == 0 {
+                    continue;
+                }
+                // going downwards so that every deposit is harvested at most once
+                for rocks in (0..missing).rev() {
+                    let (energy, chosen) = match &cheapest[rocks] {
+                        Some(entry) => entry.clone(),
+                        None => continue
+                    };
+                    let target = (rocks + deposit.rocks).min(missing);
+                    let energy = energy + deposit.energy(config.destroy_energy);
+                    if cheapest[target].as_ref().map_or(true, |(best, _)| energy < *best) {
+                        let mut chosen = chosen;
+                        chosen.push(index);
+                        cheapest[target] = Some((energy, chosen));
+                    }
+                }
+            }
+
+            let (mut chosen, shortfall): (Vec<RockDeposit>, usize) = match cheapest[missing].take() {
+                Some((_, chosen)) => (chosen.into_iter().map(|index| deposits[index].clone()).collect(), 0),
+                None => {
+                    let rocks: usize = deposits.iter().map(|deposit| deposit.rocks).sum();
+                    (deposits, missing - rocks)
+                }
+            };
+            chosen.sort_by_key(|deposit| (deposit.path_cost, deposit.coordinates));
+
+            let ticks_until_bridge = if shortfall == 0 {
+                Some(chosen.len() * config.ticks_per_deposit + config.ticks_per_bridge)
+            } else {
+                None
+            };
+            RockBudget {
+                needed,
+                available,
+                harvest_energy: chosen.iter().map(|deposit| deposit.energy(config.destroy_energy)).sum(),
+                deposits: chosen,
+                bridge_energy,
+                shortfall,
+                ticks_until_bridge
+            }
+        }
+        /// Returns true if the robot already has the rocks to finish the bridge
+        pub fn is_affordable(&self) -> bool {
+            self.available >= self.needed
+        }
+        /// Returns the energy needed to harvest the deposits and build the bridge
+        pub fn total_energy(&self) -> usize {
+            self.harvest_energy + self.bridge_energy
+        }
+    }
+
+    impl Display for RockBudget {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Rock budget: {}/{} rocks, {} deposits to harvest, ~{} energy, ",
+                   self.available, self.needed, self.deposits.len(), self.total_energy())?;
+            match self.ticks_until_bridge {
+                Some(ticks) => write!(f, "bridge in ~{} ticks", ticks),
+                None => write!(f, "{} rocks missing from the known deposits", self.shortfall)
+            }
+        }
+    }
+
+    impl MinerRobot {
+        /// Plans which rocks to gather for the next bridge, and stores the result in `rock_budget`
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        ///
+        /// # Returns
+        ///
+        /// The RockBudget, or a MinerError if there is no bridge to build
+        ///
+        /// # Notes
+        ///
+        /// If a bridge has been started only the rocks for the tiles that are left are counted
+        pub fn plan_rock_budget(&mut self, world: &mut World) -> Result<RockBudget, MinerError> {
+            self.rock_budget = None;
+            let (needed, bridge_energy) = match &self.bridge_progress {
+                Some(progress) => (progress.remaining_rocks(), progress.plan.estimated_energy),
+                None => {
+                    let plan = self.lock_bridge_plan(world)?;
+                    (plan.total_rocks, plan.estimated_energy)
+                }
+            };
+
+            // the cost vector only contains the coordinates, the rocks are read from the known map
+            let map = self.get_known_map(world);
+            let deposits = self.get_cost_vector_to_content(world, Content::Rock(1))?
+                .into_iter()
+                .filter_map(|(path_cost, (row, col))| match map.tile(row as i32, col as i32)?.content {
+                    Content::Rock(rocks) => Some(RockDeposit { coordinates: (row, col), rocks, path_cost }),
+                    _ => None
+                })
+                .collect();
+
+            let budget = RockBudget::plan(needed, self.rocks_collected, deposits, bridge_energy, &self.config);
+            self.rock_budget = Some(budget.clone());
+            Ok(budget)
+        }
+    }
+}
@@ -0,0 +1,298 @@
+pub mod config {
+    // std
+    use std::fmt::{Display, Formatter};
+    use std::fs;
+    use std::path::Path;
+
+    // MinerRobot
+    use crate::{COLLECT_DIRECTION, COLLECT_RANGE, CONNECT_ALL_ISLANDS, DESTROY_ENERGY, ENERGY_BUDGET, GOAL_QUANTITY, MAX_MOVE_RETRIES, MIN_ENERGY, RETARGET_MARGIN, SCAN_DISTANCE, SCAN_INCREASE, STATE_HISTORY_SIZE, TARGET_STRATEGY, THRESHOLD, TICKS_PER_BRIDGE, TICKS_PER_DEPOSIT, TargetStrategy, UNKNOWN_POLICY, UnknownPolicy};
+
+    // robotics lib
+    use robotics_lib::interface::Direction;
+
+    // other
+    use serde::{Deserialize, Serialize};
+
+    // the robot's energy can never exceed this value
+    const MAX_ENERGY: usize = 1000;
+
+    /// Errors that can occur while building or loading a MinerConfig
+    #[derive(Debug)]
+    pub enum ConfigError {
+        Io(std::io::Error),
+        Parse(String),
+        UnsupportedFormat(String),
+        Invalid(String)
+    }
+
+    impl Display for ConfigError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            match self {
+                ConfigError::Io(e) => write!(f, "Cannot read the configuration file: {}", e),
+                ConfigError::Parse(msg) => write!(f, "Cannot parse the configuration: {}", msg),
+                ConfigError::UnsupportedFormat(ext) => write!(f, "Unsupported configuration format: {}", ext),
+                ConfigError::Invalid(msg) => write!(f, "Invalid configuration: {}", msg)
+            }
+        }
+    }
+
+    impl std::error::Error for ConfigError {}
+
+    /// Runtime configuration of the MinerRobot
+    ///
+    /// # Notes
+    ///
+    /// Every field has a default value, so a configuration file only needs to contain the values that change.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct MinerConfig {
+        /// quantity of rocks required to complete a goal
+        pub goal_quantity: u32,
+        /// maximum amount of energy the spyglass can use
+        pub energy_budget: usize,
+        /// value that determines whether the spyglass discovers a certain area
+        pub threshold: f64,
+        /// initial scan distance of the spyglass
+        pub scan_distance: usize,
+        /// how much the scan distance increases when no content is found
+        pub scan_increase: usize,
+        /// the robot recharges when its energy drops below this value
+        pub min_energy: usize,
+        /// range used when collecting all the content around the robot
+        pub collect_range: usize,
+        /// direction in which the road paving machine collects rocks
+        #[serde(with = "direction_serde")]
+        pub collect_direction: Direction,
+        /// rocks a new bridge has to save, compared to the locked one, to become the new target
+        pub retarget_margin: usize,
+        /// how many times a failed move gets replanned before giving up
+        pub max_move_retries: usize,
+        /// amount of state transitions kept in the robot's history
+        pub state_history_size: usize,
+        /// whether the robot builds the bridges that connect every island, instead of only the one chosen by the target strategy
+        pub connect_all_islands: bool,
+        /// how the robot chooses the island the next bridge leads to
+        pub target_strategy: TargetStrategy,
+        /// whether the planners treat the undiscovered tiles as water, as land or as tiles to stay away from
+        pub unknown_policy: UnknownPolicy,
+        /// energy used to collect the rocks of a deposit, used by the rock budget
+        pub destroy_energy: usize,
+        /// ticks the robot spends on each deposit, used to estimate when the next bridge starts
+        pub ticks_per_deposit: usize,
+        /// ticks the robot spends reaching and paving the bridge, used to estimate when the next bridge starts
+        pub ticks_per_bridge: usize
+    }
+
+    impl Default for MinerConfig {
+        fn default() -> Self {
+            Self {
+                goal_quantity: GOAL_QUANTITY,
+                energy_budget: ENERGY_BUDGET,
+                threshold: THRESHOLD,
+                scan_distance: SCAN_DISTANCE,
+                scan_increase: SCAN_INCREASE,
+                min_energy: MIN_ENERGY,
+                collect_range: COLLECT_RANGE,
+                collect_direction: COLLECT_DIRECTION,
+                retarget_margin: RETARGET_MARGIN,
+                max_move_retries: MAX_MOVE_RETRIES,
+                state_history_size: STATE_HISTORY_SIZE,
+                connect_all_islands: CONNECT_ALL_ISLANDS,
+                target_strategy: TARGET_STRATEGY,
+                unknown_policy: UNKNOWN_POLICY,
+                destroy_energy: DESTROY_ENERGY,
+                ticks_per_deposit: TICKS_PER_DEPOSIT,
+                ticks_per_bridge: TICKS_PER_BRIDGE
+            }
+        }
+    }
+
+    impl MinerConfig {
+        /// Returns a builder initialized with the default values
+        pub fn builder() -> MinerConfigBuilder {
+            MinerConfigBuilder::new()
+        }
+        /// Loads and validates a configuration file
+        ///
+        /// # Arguments
+        ///
+        /// * `path` - the path of a `.toml` or `.json` file
+        ///
+        /// # Returns
+        ///
+        /// The validated configuration or a ConfigError
+        pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+            let path = path.as_ref();
+            let contents = fs::read_to_string(path).map_err(ConfigError::Io)?;
+            match path.extension().and_then(|ext| ext.to_str()) {
+                Some("toml") => Self::from_toml_str(&contents),
+                Some("json") => Self::from_json_str(&contents),
+                other => Err(ConfigError::UnsupportedFormat(other.unwrap_or("none").to_string()))
+            }
+        }
+        /// Parses and validates a TOML configuration
+        ///
+        /// # Arguments
+        ///
+        /// * `contents` - the TOML document
+        pub fn from_toml_str(contents: &str) -> Result<Self, ConfigError> {
+            let config: MinerConfig = toml::from_str(contents).map_err(|e| ConfigError::Parse(e.to_string()))?;
+            config.validate()?;
+            Ok(config)
+        }
+        /// Parses and validates a JSON configuration
+        ///
+        /// # Arguments
+        ///
+        /// * `contents` - the JSON document
+        pub fn from_json_str(contents: &str) -> Result<Self, ConfigError> {
+            let config: MinerConfig = serde_json::from_str(contents).map_err(|e| ConfigError::Parse(e.to_string()))?;
+            config.validate()?;
+            Ok(config)
+        }
+        /// Checks that every value is usable by the robot
+        ///
+        /// # Returns
+        ///
+        /// Ok if the configuration is valid, otherwise a ConfigError::Invalid describing the first wrong value
+        pub fn validate(&self) -> Result<(), ConfigError> {
+            if self.goal_quantity == 0 {
+                return Err(ConfigError::Invalid("goal_quantity must be greater than 0".to_string()));
+            }
+            if self.energy_budget == 0 {
+                return Err(ConfigError::Invalid("energy_budget must be greater than 0".to_string()));
+            }
+            if !(0.0..=1.0).contains(&self.threshold) {
+                return Err(ConfigError::Invalid(format!("threshold must be between 0 and 1, got {}", self.threshold)));
+            }
+            if self.scan_distance == 0 {
+                return Err(ConfigError::Invalid("scan_distance must be greater than 0".to_string()));
+            }
+            if self.scan_increase == 0 {
+                return Err(ConfigError::Invalid("scan_increase must be greater than 0".to_string()));
+            }
+            if self.min_energy >= MAX_ENERGY {
+                return Err(ConfigError::Invalid(format!("min_energy must be lower than {}", MAX_ENERGY)));
+            }
+            if self.collect_range == 0 {
+                return Err(ConfigError::Invalid("collect_range must be greater than 0".to_string()));
+            }
+            if self.state_history_size == 0 {
+                return Err(ConfigError::Invalid("state_history_size must be greater than 0".to_string()));
+            }
+            Ok(())
+        }
+    }
+
+    /// Builder for the MinerConfig, every value that isn't set keeps its default
+    #[derive(Debug, Clone, Default)]
+    pub struct MinerConfigBuilder {
+        config: MinerConfig
+    }
+
+    impl MinerConfigBuilder {
+        pub fn new() -> Self {
+            Self { config: MinerConfig::default() }
+        }
+        pub fn goal_quantity(mut self, goal_quantity: u32) -> Self {
+            self.config.goal_quantity = goal_quantity;
+            self
+        }
+        pub fn energy_budget(mut self, energy_budget: usize) -> Self {
+            self.config.energy_budget = energy_budget;
+            self
+        }
+        pub fn threshold(mut self, threshold: f64) -> Self {
+            self.config.threshold = threshold;
+            self
+        }
+        pub fn scan_distance(mut self, scan_distance: usize) -> Self {
+            self.config.scan_distance = scan_distance;
+            self
+        }
+        pub fn scan_increase(mut self, scan_increase: usize) -> Self {
+            self.config.scan_increase = scan_increase;
+            self
+        }
+        pub fn min_energy(mut self, min_energy: usize) -> Self {
+            self.config.min_energy = min_energy;
+            self
+        }
+        pub fn collect_range(mut self, collect_range: usize) -> Self {
+            self.config.collect_range = collect_range;
+            self
+        }
+        pub fn collect_direction(mut self, collect_direction: Direction) -> Self {
+            self.config.collect_direction = collect_direction;
+            self
+        }
+        pub fn retarget_margin(mut self, retarget_margin: usize) -> Self {
+            self.config.retarget_margin = retarget_margin;
+            self
+        }
+        pub fn max_move_retries(mut self, max_move_retries: usize) -> Self {
+            self.config.max_move_retries = max_move_retries;
+            self
+        }
+        pub fn state_history_size(mut self, state_history_size: usize) -> Self {
+            self.config.state_history_size = state_history_size;
+            self
+        }
+        pub fn connect_all_islands(mut self, connect_all_islands: bool) -> Self {
+            self.config.connect_all_islands = connect_all_islands;
+            self
+        }
+        pub fn target_strategy(mut self, target_strategy: TargetStrategy) -> Self {
+            self.config.target_strategy = target_strategy;
+            self
+        }
+        pub fn unknown_policy(mut self, unknown_policy: UnknownPolicy) -> Self {
+            self.config.unknown_policy = unknown_policy;
+            self
+        }
+        pub fn destroy_energy(mut self, destroy_energy: usize) -> Self {
+            self.config.destroy_energy = destroy_energy;
+            self
+        }
+        pub fn ticks_per_deposit(mut self, ticks_per_deposit: usize) -> Self {
+            self.config.ticks_per_deposit = ticks_per_deposit;
+            self
+        }
+        pub fn ticks_per_bridge(mut self, ticks_per_bridge: usize) -> Self {
+            self.config.ticks_per_bridge = ticks_per_bridge;
+            self
+        }
+        /// Validates and returns the configuration
+        pub fn build(self) -> Result<MinerConfig, ConfigError> {
+            self.config.validate()?;
+            Ok(self.config)
+        }
+    }
+
+    /// Serializes a Direction as a lowercase string ("up", "down", "left", "right")
+    mod direction_serde {
+        use robotics_lib::interface::Direction;
+        use serde::{Deserialize, Deserializer, Serializer};
+        use serde::de::Error;
+
+        pub fn serialize<S: Serializer>(direction: &Direction, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(match direction {
+                Direction::Up => "up",
+                Direction::Down => "down",
+                Direction::Left => "left",
+                Direction::Right => "right"
+            })
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Direction, D::Error> {
+            let value = String::deserialize(deserializer)?;
+            match value.to_lowercase().as_str() {
+                "up" => Ok(Direction::Up),
+                "down" => Ok(Direction::Down),
+                "left" => Ok(Direction::Left),
+                "right" => Ok(Direction::Right),
+                other => Err(D::Error::custom(format!("unknown direction: {}", other)))
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+pub mod cost {
+    // robotics lib
+    use robotics_lib::world::tile::TileType;
+
+    /// The rules used by the MinerRobot to decide where it can walk and how much paving costs
+    pub trait TileCostModel: Send {
+        /// Returns the rocks needed to pave a tile
+        fn paving_cost(&self, tile_type: &TileType) -> usize;
+        /// Returns the energy used to walk on a tile, without elevation and weather
+        fn walk_energy(&self, tile_type: &TileType) -> usize;
+        /// Returns true if the robot can walk on a tile
+        fn is_walkable(&self, tile_type: &TileType) -> bool;
+        /// Returns true if a non walkable tile can be paved with rocks
+        fn is_pavable(&self, tile_type: &TileType) -> bool;
+    }
+
+    /// The rules the MinerRobot has always used
+    ///
+    /// # Notes
+    ///
+    /// Water and lava are the only tiles that are not walkable, so they are the only ones that get paved
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct DefaultCostModel;
+
+    impl TileCostModel for DefaultCostModel {
+        fn paving_cost(&self, tile_type: &TileType) -> usize {
+            match tile_type {
+                TileType::DeepWater => 3,
+                TileType::Lava => 3,
+                TileType::ShallowWater => 2,
+                TileType::Mountain => 0,
+                _ => 1
+            }
+        }
+        fn walk_energy(&self, tile_type: &TileType) -> usize {
+            tile_type.properties().cost()
+        }
+        fn is_walkable(&self, tile_type: &TileType) -> bool {
+            match tile_type {
+                TileType::DeepWater => false,
+                TileType::Lava => false,
+                TileType::ShallowWater => false,
+                _ => true
+            }
+        }
+        fn is_pavable(&self, tile_type: &TileType) -> bool {
+            !self.is_walkable(tile_type)
+        }
+    }
+
+    /// Same as the DefaultCostModel, but walls and teleports can be neither walked on nor paved
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct StrictCostModel;
+
+    impl TileCostModel for StrictCostModel {
+        fn paving_cost(&self, tile_type: &TileType) -> usize {
+            DefaultCostModel.paving_cost(tile_type)
+        }
+        fn walk_energy(&self, tile_type: &TileType) -> usize {
+            DefaultCostModel.walk_energy(tile_type)
+        }
+        fn is_walkable(&self, tile_type: &TileType) -> bool {
+            match tile_type {
+                TileType::Wall | TileType::Teleport(_) => false,
+                _ => DefaultCostModel.is_walkable(tile_type)
+            }
+        }
+        fn is_pavable(&self, tile_type: &TileType) -> bool {
+            match tile_type {
+                TileType::Wall | TileType::Teleport(_) => false,
+                _ => !self.is_walkable(tile_type)
+            }
+        }
+    }
+}
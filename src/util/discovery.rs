@@ -1,7 +1,7 @@
 pub mod discovery {
     use robotics_lib::world::World;
     use spyglass::spyglass::SpyglassResult;
-    use crate::{ENERGY_BUDGET, MinerRobot, THRESHOLD};
+    use crate::{MinerError, MinerRobot};
 
     impl MinerRobot {
         /// Calls the discover_world method if the world hasn't been scanned yet
@@ -10,11 +10,18 @@ pub mod discovery {
         ///
         /// * `world` - the known world
         /// * `distance` - the distance from the robot, indicating the area to discover
-        pub fn scan_world(&mut self, world: &mut World, distance: usize) {
+        ///
+        /// # Returns
+        ///
+        /// MinerError::Spyglass if the discovery failed, in that case the world gets scanned again on the next call
+        pub fn scan_world(&mut self, world: &mut World, distance: usize) -> Result<(), MinerError> {
             if !self.world_scanned {
-                self.discover_world(world, distance);
+                // the spyglass discovers tiles without generating events
+                self.invalidate_known_map();
+                self.discover_world(world, distance)?;
                 self.world_scanned = true;
             }
+            Ok(())
         }
         /// Discovers the world around the robot
         ///
@@ -22,9 +29,9 @@ pub mod discovery {
         ///
         /// * `world` - the known world
         /// * `distance` - the distance from the robot, indicating the area to discover
-        fn discover_world(&mut self, world: &mut World, distance: usize) {
+        fn discover_world(&mut self, world: &mut World, distance: usize) -> Result<(), MinerError> {
             // generating a new spyglass by setting an energy budget and a threshold
-            let mut spyglass = self.create_spyglass(world, distance, ENERGY_BUDGET, THRESHOLD);
+            let mut spyglass = self.create_spyglass(world, distance, self.config.energy_budget, self.config.threshold);
 
             // discovering tiles around the robot
             let result = spyglass.new_discover(self, world);
@@ -35,7 +42,7 @@ pub mod discovery {
                     println!("Scan Complete!");
                 },
                 SpyglassResult::Failed(_) => {
-                    println!("Scan Failed!");
+                    return Err(MinerError::Spyglass(String::from("Scan Failed!")));
                 },
                 SpyglassResult::Paused => {
                     println!("Scan Paused!");
@@ -44,6 +51,7 @@ pub mod discovery {
                     println!("Scan Stopped!");
                 }
             }
+            Ok(())
         }
     }
 }
\ No newline at end of file
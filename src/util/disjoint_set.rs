@@ -0,0 +1,55 @@
+pub mod disjoint_set {
+    /// Union-find over indices, shared by the island tracker and the bridge network
+    ///
+    /// # Notes
+    ///
+    /// The smaller set is attached to the bigger one and the paths are compressed while searching,
+    /// so a sequence of operations takes almost constant time for each of them
+    #[derive(Debug, Clone, Default)]
+    pub(crate) struct DisjointSet {
+        parents: Vec<usize>,
+        sizes: Vec<usize>
+    }
+
+    impl DisjointSet {
+        /// Creates `size` sets, each one containing only its own index
+        pub(crate) fn new(size: usize) -> Self {
+            Self { parents: (0..size).collect(), sizes: vec![1; size] }
+        }
+        /// Moves an item back to a set of its own
+        ///
+        /// # Notes
+        ///
+        /// The items that pointed to it are not moved, so every item of its old set has to be reset as well
+        pub(crate) fn reset(&mut self, item: usize) {
+            self.parents[item] = item;
+            self.sizes[item] = 1;
+        }
+        /// Returns the item that represents the set of an item
+        pub(crate) fn find(&mut self, item: usize) -> usize {
+            let mut root = item;
+            while self.parents[root] != root {
+                root = self.parents[root];
+            }
+            // compressing the path so that the next searches are faster
+            let mut current = item;
+            while self.parents[current] != root {
+                let next = self.parents[current];
+                self.parents[current] = root;
+                current = next;
+            }
+            root
+        }
+        /// Joins the sets of the two items, returns false if they were already joined
+        pub(crate) fn union(&mut self, first: usize, second: usize) -> bool {
+            let (first, second) = (self.find(first), self.find(second));
+            if first == second {
+                return false;
+            }
+            let (big, small) = if self.sizes[first] >= self.sizes[second] { (first, second) } else { (second, first) };
+            self.parents[small] = big;
+            self.sizes[big] += self.sizes[small];
+            true
+        }
+    }
+}
@@ -0,0 +1,47 @@
+pub mod energy {
+    /// Keeps track of the energy consumed and recharged by the robot, fed by the Runner's events
+    ///
+    /// # Notes
+    ///
+    /// The Dynamo tool replaces the robot's energy without sending an event,
+    /// so those recharges are not part of the ledger
+    #[derive(Debug, Clone, Default, PartialEq)]
+    pub struct EnergyLedger {
+        /// energy consumed since the robot was created
+        pub consumed: usize,
+        /// energy recharged since the robot was created
+        pub recharged: usize,
+        /// energy consumed during the current tick
+        pub consumed_this_tick: usize,
+        /// energy recharged during the current tick
+        pub recharged_this_tick: usize,
+        /// lowest energy level observed after an action, None before the first one
+        pub lowest_level: Option<usize>
+    }
+
+    impl EnergyLedger {
+        /// Records the energy consumed by an action
+        pub fn consume(&mut self, amount: usize) {
+            self.consumed += amount;
+            self.consumed_this_tick += amount;
+        }
+        /// Records the energy gained by a recharge
+        pub fn recharge(&mut self, amount: usize) {
+            self.recharged += amount;
+            self.recharged_this_tick += amount;
+        }
+        /// Records the robot's energy level, keeping the lowest one
+        pub fn observe(&mut self, level: usize) {
+            self.lowest_level = Some(self.lowest_level.map_or(level, |lowest| lowest.min(level)));
+        }
+        /// Resets the per-tick counters, called at the start of every tick
+        pub fn start_tick(&mut self) {
+            self.consumed_this_tick = 0;
+            self.recharged_this_tick = 0;
+        }
+        /// Returns the difference between the recharged and the consumed energy
+        pub fn balance(&self) -> i64 {
+            self.recharged as i64 - self.consumed as i64
+        }
+    }
+}
@@ -0,0 +1,113 @@
+pub mod error {
+    // std
+    use std::fmt::{Display, Formatter};
+
+    // MinerRobot
+    use crate::{ConfigError, RobotState};
+
+    // robotics lib
+    use robotics_lib::utils::LibError;
+
+    // tools
+    use bessie::bessie::RpmError;
+
+    /// Errors returned by the MinerRobot's methods
+    #[derive(Debug)]
+    pub enum MinerError {
+        /// error returned by the robotics lib interface
+        Lib(LibError),
+        /// error returned by the road paving machine
+        Rpm(RpmError),
+        /// the spyglass could not complete the discovery
+        Spyglass(String),
+        /// the robot is not standing on any known island
+        NoRobotIsland,
+        /// there is no other island to build a bridge to
+        NoTargetIsland,
+        /// no tile with the requested content has been discovered
+        NoContentFound,
+        /// the robot couldn't reach the target tile after the given amount of attempts
+        MovementFailed { target: (usize,usize), attempts: usize },
+        /// the path to the target crosses a tile the TileCostModel doesn't allow the robot to walk on
+        ForbiddenPath { target: (usize,usize), tile: (i32,i32) },
+        /// the robot can't go from the first state to the second one
+        InvalidTransition { from: RobotState, to: RobotState },
+        /// the configuration is not valid
+        Config(ConfigError),
+        /// the bridge has been built but the planned tiles are not all walkable, or the islands are still separated
+        BridgeVerificationFailed { unpaved: Vec<(i32,i32)>, islands_merged: bool }
+    }
+
+    /// What the robot should do after a MinerError
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Recovery {
+        /// recharge the energy and try again on the next tick
+        Recharge,
+        /// widen the scan and look for new content and islands
+        Replan,
+        /// log the error and try again on the next tick
+        Skip,
+        /// end the game
+        GiveUp
+    }
+
+    impl MinerError {
+        /// Returns the recovery policy associated to the error
+        ///
+        /// # Returns
+        ///
+        /// The Recovery that the robot should apply
+        pub fn recovery(&self) -> Recovery {
+            match self {
+                MinerError::Lib(LibError::NotEnoughEnergy) => Recovery::Recharge,
+                MinerError::Rpm(RpmError::NotEnoughEnergy) => Recovery::Recharge,
+                MinerError::Lib(_) | MinerError::Rpm(_) | MinerError::Spyglass(_) => Recovery::Skip,
+                MinerError::MovementFailed { .. } | MinerError::InvalidTransition { .. } => Recovery::Skip,
+                MinerError::BridgeVerificationFailed { .. } => Recovery::Skip,
+                MinerError::NoRobotIsland | MinerError::NoTargetIsland | MinerError::NoContentFound => Recovery::Replan,
+                MinerError::ForbiddenPath { .. } => Recovery::Replan,
+                MinerError::Config(_) => Recovery::GiveUp
+            }
+        }
+    }
+
+    impl Display for MinerError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            match self {
+                MinerError::Lib(e) => write!(f, "Robotics lib error: {:?}", e),
+                MinerError::Rpm(e) => write!(f, "Road paving machine error: {:?}", e),
+                MinerError::Spyglass(msg) => write!(f, "Spyglass error: {}", msg),
+                MinerError::NoRobotIsland => write!(f, "The robot is not on a known island"),
+                MinerError::NoTargetIsland => write!(f, "There is no target island"),
+                MinerError::NoContentFound => write!(f, "The content vector is empty"),
+                MinerError::MovementFailed { target, attempts } => write!(f, "Failed to reach {:?} after {} attempts", target, attempts),
+                MinerError::ForbiddenPath { target, tile } => write!(f, "The path to {:?} crosses the forbidden tile {:?}", target, tile),
+                MinerError::InvalidTransition { from, to } => write!(f, "Invalid transition from {:?} to {:?}", from, to),
+                MinerError::Config(e) => write!(f, "{}", e),
+                MinerError::BridgeVerificationFailed { unpaved, islands_merged } => {
+                    write!(f, "The bridge is not complete, unpaved tiles: {:?}, islands merged: {}", unpaved, islands_merged)
+                }
+            }
+        }
+    }
+
+    impl std::error::Error for MinerError {}
+
+    impl From<LibError> for MinerError {
+        fn from(error: LibError) -> Self {
+            MinerError::Lib(error)
+        }
+    }
+
+    impl From<RpmError> for MinerError {
+        fn from(error: RpmError) -> Self {
+            MinerError::Rpm(error)
+        }
+    }
+
+    impl From<ConfigError> for MinerError {
+        fn from(error: ConfigError) -> Self {
+            MinerError::Config(error)
+        }
+    }
+}
@@ -0,0 +1,145 @@
+pub mod generator {
+    // std
+    use std::collections::HashMap;
+    use std::fmt::{Display, Formatter};
+
+    // robotics lib
+    use robotics_lib::world::environmental_conditions::{EnvironmentalConditions, WeatherType};
+    use robotics_lib::world::tile::{Content, Tile, TileType};
+    use robotics_lib::world::world_generator::Generator;
+
+    // default values of the generated world
+    const TIME_PROGRESSION_MINUTES: u8 = 15;
+    const STARTING_HOUR: u8 = 12;
+    const MAX_SCORE: f32 = 100.0;
+
+    /// Errors that make an in-memory world unusable
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum WorldError {
+        EmptyMap,
+        NotSquare { row: usize, len: usize, expected: usize },
+        SpawnOutOfBounds((usize, usize)),
+        ContentOutOfBounds((usize, usize))
+    }
+
+    impl Display for WorldError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            match self {
+                WorldError::EmptyMap => write!(f, "The map is empty"),
+                WorldError::NotSquare { row, len, expected } => write!(f, "Row {} has {} tiles, expected {}", row, len, expected),
+                WorldError::SpawnOutOfBounds(spawn) => write!(f, "The spawn point {:?} is out of bounds", spawn),
+                WorldError::ContentOutOfBounds(coordinates) => write!(f, "The content at {:?} is out of bounds", coordinates)
+            }
+        }
+    }
+
+    impl std::error::Error for WorldError {}
+
+    /// Generator that builds the World from a map kept in memory
+    ///
+    /// # Notes
+    ///
+    /// Every call to gen() returns the same world, so the same generator can be used to run several robots
+    #[derive(Debug, Clone)]
+    pub struct MemoryWorldGenerator {
+        map: Vec<Vec<Tile>>,
+        spawn: (usize, usize),
+        conditions: EnvironmentalConditions,
+        max_score: f32,
+        score_table: Option<HashMap<Content, f32>>
+    }
+
+    impl MemoryWorldGenerator {
+        /// Creates a new generator given the map and the spawn point
+        ///
+        /// # Arguments
+        ///
+        /// * `map` - the tiles of the world, it has to be a square matrix
+        /// * `spawn` - the robot's starting coordinates (row, col)
+        ///
+        /// # Returns
+        ///
+        /// The generator, or a WorldError if the map or the spawn point are not valid
+        pub fn new(map: Vec<Vec<Tile>>, spawn: (usize, usize)) -> Result<Self, WorldError> {
+            let size = map.len();
+            if size == 0 {
+                return Err(WorldError::EmptyMap);
+            }
+            for (row, tiles) in map.iter().enumerate() {
+                if tiles.len() != size {
+                    return Err(WorldError::NotSquare { row, len: tiles.len(), expected: size });
+                }
+            }
+            if spawn.0 >= size || spawn.1 >= size {
+                return Err(WorldError::SpawnOutOfBounds(spawn));
+            }
+
+            Ok(Self {
+                map,
+                spawn,
+                conditions: EnvironmentalConditions::new(&[WeatherType::Sunny], TIME_PROGRESSION_MINUTES, STARTING_HOUR)
+                    .expect("Error while creating the environmental conditions"),
+                max_score: MAX_SCORE,
+                score_table: None
+            })
+        }
+        /// Creates a new generator given only the tile types, every tile has no content and no elevation
+        ///
+        /// # Arguments
+        ///
+        /// * `tile_types` - the tile types of the world, it has to be a square matrix
+        /// * `spawn` - the robot's starting coordinates (row, col)
+        pub fn from_tile_types(tile_types: Vec<Vec<TileType>>, spawn: (usize, usize)) -> Result<Self, WorldError> {
+            let map = tile_types.into_iter()
+                .map(|row| row.into_iter().map(|tile_type| Tile { tile_type, content: Content::None, elevation: 0 }).collect())
+                .collect();
+            Self::new(map, spawn)
+        }
+        /// Sets the environmental conditions of the world
+        pub fn with_conditions(mut self, conditions: EnvironmentalConditions) -> Self {
+            self.conditions = conditions;
+            self
+        }
+        /// Sets the maximum score of the world
+        pub fn with_max_score(mut self, max_score: f32) -> Self {
+            self.max_score = max_score;
+            self
+        }
+        /// Sets the score associated to each content
+        pub fn with_score_table(mut self, score_table: HashMap<Content, f32>) -> Self {
+            self.score_table = Some(score_table);
+            self
+        }
+        /// Sets the content of a single tile
+        ///
+        /// # Arguments
+        ///
+        /// * `(row, col)` - the tile's coordinates
+        /// * `content` - the new content
+        ///
+        /// # Returns
+        ///
+        /// The generator, or WorldError::ContentOutOfBounds if the tile is not in the map
+        pub fn with_content(mut self, (row, col): (usize, usize), content: Content) -> Result<Self, WorldError> {
+            match self.map.get_mut(row).and_then(|tiles| tiles.get_mut(col)) {
+                Some(tile) => tile.content = content,
+                None => return Err(WorldError::ContentOutOfBounds((row, col)))
+            }
+            Ok(self)
+        }
+        /// Returns the map that will be generated
+        pub fn map(&self) -> &Vec<Vec<Tile>> {
+            &self.map
+        }
+        /// Returns the robot's spawn point
+        pub fn spawn(&self) -> (usize, usize) {
+            self.spawn
+        }
+    }
+
+    impl Generator for MemoryWorldGenerator {
+        fn gen(&mut self) -> (Vec<Vec<Tile>>, (usize, usize), EnvironmentalConditions, f32, Option<HashMap<Content, f32>>) {
+            (self.map.clone(), self.spawn, self.conditions.clone(), self.max_score, self.score_table.clone())
+        }
+    }
+}
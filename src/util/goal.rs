@@ -1,7 +1,6 @@
 pub mod goal {
     // MinerRobot
     use crate::MinerRobot;
-    use crate::GOAL_QUANTITY;
 
     // robotics lib
     use robotics_lib::world::tile::Content;
@@ -14,7 +13,7 @@ pub mod goal {
         /// Sets a new goal if the tracker is empty, otherwise it cleans the completed goals
         pub fn handle_goals(&mut self) {
             if self.goal_tracker.get_goals().len() == 0 {
-                self.set_goal_by_content(GoalType::GetItems, Content::Rock(1), GOAL_QUANTITY);
+                self.set_goal_by_content(GoalType::GetItems, Content::Rock(1), self.config.goal_quantity);
             } else {
                 self.goal_tracker.clean_completed_goals();
             }
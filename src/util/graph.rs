@@ -0,0 +1,135 @@
+pub mod graph {
+    // std
+    use std::fs;
+    use std::io;
+    use std::path::Path;
+
+    // MinerRobot
+    use crate::{KnownMap, MinerRobot};
+
+    // serde
+    use serde::{Deserialize, Serialize};
+
+    /// An island of the IslandGraph
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct IslandNode {
+        /// index of the island in get_islands()
+        pub index: usize,
+        /// stable id of the island, see IslandTracker
+        pub id: usize,
+        /// amount of tiles of the island
+        pub size: usize
+    }
+
+    /// A candidate bridge between two islands of the IslandGraph
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct BridgeEdge {
+        /// index of the island with the lower index
+        pub source: usize,
+        /// index of the other island
+        pub target: usize,
+        /// tile of the source island where the bridge starts
+        pub start: (i32, i32),
+        /// tile of the target island where the bridge ends
+        pub end: (i32, i32),
+        /// rocks needed to pave the bridge
+        pub rocks: usize
+    }
+
+    /// The known islands and the bridges that could connect every pair of them
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct IslandGraph {
+        pub nodes: Vec<IslandNode>,
+        /// one edge for each pair of islands, sorted by source and then by target
+        pub edges: Vec<BridgeEdge>
+    }
+
+    impl IslandGraph {
+        /// Writes the graph in the Graphviz DOT format
+        ///
+        /// # Returns
+        ///
+        /// An undirected graph where the nodes are labelled with the size of the islands and the edges with the rocks
+        pub fn to_dot(&self) -> String {
+            let mut dot = String::from("graph islands {\n");
+            for node in &self.nodes {
+                dot.push_str(&format!("    {} [label=\"island {} (#{})\\n{} tiles\"];\n", node.index, node.index, node.id, node.size));
+            }
+            for edge in &self.edges {
+                dot.push_str(&format!("    {} -- {} [label=\"{} rocks\"];\n", edge.source, edge.target, edge.rocks));
+            }
+            dot.push_str("}\n");
+            dot
+        }
+        /// Writes the graph as a JSON document
+        pub fn to_json(&self) -> Result<String, serde_json::Error> {
+            serde_json::to_string_pretty(self)
+        }
+        /// Writes the graph to a DOT file and to a JSON file
+        ///
+        /// # Arguments
+        ///
+        /// * `dot_path` - the path of the DOT file
+        /// * `json_path` - the path of the JSON file
+        pub fn write<P: AsRef<Path>, Q: AsRef<Path>>(&self, dot_path: P, json_path: Q) -> io::Result<()> {
+            fs::write(dot_path, self.to_dot())?;
+            fs::write(json_path, self.to_json()?)
+        }
+    }
+
+    impl MinerRobot {
+        /// Builds the graph of the islands of the known map, used to debug the bridges
+        ///
+        /// # Arguments
+        ///
+        /// * `map` - the known world
+        ///
+        /// # Returns
+        ///
+        /// The IslandGraph with an edge for every pair of islands
+        ///
+        /// # Notes
+        ///
+        /// The edges join the closest points of the two islands, and their rocks are the paving cost of the L-shaped path between them,
+        /// so they can be more expensive than the bridges chosen by plan_bridge_network().
+        /// Every pair of islands is compared, so the graph gets slow to build on maps with many islands
+        pub fn get_island_graph(&self, map: &KnownMap) -> IslandGraph {
+            let tracker = self.sync_island_tracker(map);
+            let islands = tracker.islands();
+
+            let nodes = islands.iter()
+                .zip(tracker.island_ids())
+                .enumerate()
+                .map(|(index, (island, &id))| IslandNode { index, id, size: island.len() })
+                .collect();
+
+            let mut edges = Vec::new();
+            for (source, source_island) in islands.iter().enumerate() {
+                for (target, target_island) in islands.iter().enumerate().skip(source + 1) {
+                    // get_closest_points() returns the tile of the target island first
+                    if let Some((end, start)) = self.get_closest_points(map, source_island, target_island) {
+                        let rocks = self.get_paving_cost(map, start, end);
+                        edges.push(BridgeEdge { source, target, start, end, rocks });
+                    }
+                }
+            }
+            IslandGraph { nodes, edges }
+        }
+        /// Writes the graph of the islands of the known map to a DOT file and to a JSON file
+        ///
+        /// # Arguments
+        ///
+        /// * `map` - the known world
+        /// * `dot_path` - the path of the DOT file
+        /// * `json_path` - the path of the JSON file
+        ///
+        /// # Returns
+        ///
+        /// The IslandGraph that has been written, or the io::Error of the first file that couldn't be written
+        pub fn export_island_graph<P: AsRef<Path>, Q: AsRef<Path>>(&self, map: &KnownMap, dot_path: P, json_path: Q) -> io::Result<IslandGraph> {
+            let graph = self.get_island_graph(map);
+            graph.write(dot_path, json_path)?;
+            Ok(graph)
+        }
+    }
+}
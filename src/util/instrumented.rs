@@ -0,0 +1,174 @@
+pub mod instrumented {
+    // std
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    // robotics lib
+    use robotics_lib::energy::Energy;
+    use robotics_lib::event::events::Event;
+    use robotics_lib::runner::backpack::BackPack;
+    use robotics_lib::runner::Runnable;
+    use robotics_lib::world::coordinates::Coordinate;
+    use robotics_lib::world::World;
+
+    /// The kind of an Event, without its data
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum EventKind {
+        Ready,
+        Terminated,
+        TimeChanged,
+        DayChanged,
+        EnergyRecharged,
+        EnergyConsumed,
+        Moved,
+        TileContentUpdated,
+        AddedToBackpack,
+        RemovedFromBackpack
+    }
+
+    impl From<&Event> for EventKind {
+        fn from(event: &Event) -> Self {
+            match event {
+                Event::Ready => EventKind::Ready,
+                Event::Terminated => EventKind::Terminated,
+                Event::TimeChanged(_) => EventKind::TimeChanged,
+                Event::DayChanged(_) => EventKind::DayChanged,
+                Event::EnergyRecharged(_) => EventKind::EnergyRecharged,
+                Event::EnergyConsumed(_) => EventKind::EnergyConsumed,
+                Event::Moved(_, _) => EventKind::Moved,
+                Event::TileContentUpdated(_, _) => EventKind::TileContentUpdated,
+                Event::AddedToBackpack(_, _) => EventKind::AddedToBackpack,
+                Event::RemovedFromBackpack(_, _) => EventKind::RemovedFromBackpack
+            }
+        }
+    }
+
+    /// Measurements taken by the Instrumented wrapper
+    #[derive(Debug, Clone, Default)]
+    pub struct RunStats {
+        /// amount of ticks processed
+        pub ticks: usize,
+        /// wall time of the last tick
+        pub last_tick_time: Duration,
+        /// longest wall time of a tick
+        pub max_tick_time: Duration,
+        /// wall time of all the ticks
+        pub total_tick_time: Duration,
+        /// amount of events sent by the Runner itself, by kind, see Instrumented
+        pub event_counts: HashMap<EventKind, usize>,
+        /// energy level after the last tick minus the level before it
+        pub last_energy_delta: i64,
+        /// sum of the energy deltas of all the ticks
+        pub total_energy_delta: i64
+    }
+
+    impl RunStats {
+        /// Returns the average wall time of a tick
+        pub fn mean_tick_time(&self) -> Duration {
+            if self.ticks == 0 {
+                return Duration::ZERO;
+            }
+            self.total_tick_time / self.ticks as u32
+        }
+        /// Returns how many events of the given kind have been sent by the Runner
+        pub fn event_count(&self, kind: EventKind) -> usize {
+            self.event_counts.get(&kind).copied().unwrap_or(0)
+        }
+    }
+
+    /// Runnable that delegates everything to another Runnable and measures its behaviour
+    ///
+    /// # Notes
+    ///
+    /// The stats are shared, so a handle taken with stats_handle() can be read after the wrapper has been given to the Runner.
+    ///
+    /// Only the events sent by the Runner itself, such as Ready, TimeChanged and DayChanged, reach the wrapper:
+    /// the interface functions called by the wrapped robot send their events (Moved, EnergyConsumed, ...) to the robot
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let robot = Instrumented::new(MinerRobot::new());
+    /// let stats = robot.stats_handle();
+    /// let mut runner = Runner::new(Box::new(robot), &mut generator)?;
+    /// runner.game_tick()?;
+    /// println!("{:?}", stats.lock().unwrap().last_tick_time);
+    /// ```
+    pub struct Instrumented<R: Runnable> {
+        inner: R,
+        stats: Arc<Mutex<RunStats>>
+    }
+
+    impl<R: Runnable> Instrumented<R> {
+        /// Wraps the given Runnable
+        pub fn new(inner: R) -> Self {
+            Self {
+                inner,
+                stats: Arc::new(Mutex::new(RunStats::default()))
+            }
+        }
+        /// Returns a copy of the stats collected so far
+        pub fn stats(&self) -> RunStats {
+            self.stats.lock().unwrap().clone()
+        }
+        /// Returns the shared stats
+        pub fn stats_handle(&self) -> Arc<Mutex<RunStats>> {
+            self.stats.clone()
+        }
+        /// Returns the wrapped Runnable
+        pub fn inner(&self) -> &R {
+            &self.inner
+        }
+        /// Returns the wrapped Runnable as mutable
+        pub fn inner_mut(&mut self) -> &mut R {
+            &mut self.inner
+        }
+        /// Unwraps the Runnable
+        pub fn into_inner(self) -> R {
+            self.inner
+        }
+    }
+
+    impl<R: Runnable> Runnable for Instrumented<R> {
+        fn process_tick(&mut self, world: &mut World) {
+            let energy_before = self.inner.get_energy().get_energy_level() as i64;
+            let start = Instant::now();
+
+            self.inner.process_tick(world);
+
+            let elapsed = start.elapsed();
+            let energy_delta = self.inner.get_energy().get_energy_level() as i64 - energy_before;
+
+            let mut stats = self.stats.lock().unwrap();
+            stats.ticks += 1;
+            stats.last_tick_time = elapsed;
+            stats.max_tick_time = stats.max_tick_time.max(elapsed);
+            stats.total_tick_time += elapsed;
+            stats.last_energy_delta = energy_delta;
+            stats.total_energy_delta += energy_delta;
+        }
+        fn handle_event(&mut self, event: Event) {
+            *self.stats.lock().unwrap().event_counts.entry(EventKind::from(&event)).or_insert(0) += 1;
+            self.inner.handle_event(event)
+        }
+        fn get_energy(&self) -> &Energy {
+            self.inner.get_energy()
+        }
+        fn get_energy_mut(&mut self) -> &mut Energy {
+            self.inner.get_energy_mut()
+        }
+        fn get_coordinate(&self) -> &Coordinate {
+            self.inner.get_coordinate()
+        }
+        fn get_coordinate_mut(&mut self) -> &mut Coordinate {
+            self.inner.get_coordinate_mut()
+        }
+        fn get_backpack(&self) -> &BackPack {
+            self.inner.get_backpack()
+        }
+        fn get_backpack_mut(&mut self) -> &mut BackPack {
+            self.inner.get_backpack_mut()
+        }
+    }
+}
@@ -1,124 +1,112 @@
 pub mod island {
-    // MinerRobot
-    use crate::{MinerRobot};
+    // std
+    use std::cell::Ref;
+    use std::sync::Arc;
 
-    // robotics lib
-    use robotics_lib::world::tile::Tile;
+    // MinerRobot
+    use crate::{IslandTracker, KnownMap, MinerRobot};
 
     impl MinerRobot {
-        /// Verifies if a position is valid or not
+        /// Brings the island tracker up to date with a map
         ///
         /// # Arguments
         ///
         /// * `map` - the known world
-        /// * `row` - the row of the coordinate that we are analyzing
-        /// * `col` - the column of the coordinate that we are analyzing
-        /// * `visited` - a matrix that keeps track of the visited Coordinates
         ///
         /// # Returns
         ///
-        /// A bool corresponding to whether moving to that tile is possible or not
-        fn is_valid_move(&self, map: &Vec<Vec<Tile>>, row: i32, col: i32, visited: &Vec<Vec<bool>>) -> bool {
-            self.is_in_bounds(map,row,col) && self.is_walkable(&map[row as usize][col as usize].tile_type) && !visited[row as usize][col as usize]
+        /// The tracker, borrowed until the returned value is dropped
+        ///
+        /// # Notes
+        ///
+        /// The tracker follows the robot's cached map through the tile events, so the map is read in full
+        /// only the first time the tracker sees its revision, and even then only the tiles that changed are applied
+        pub(crate) fn sync_island_tracker(&self, map: &KnownMap) -> Ref<'_, IslandTracker> {
+            let synced = {
+                let tracker = self.island_tracker.borrow();
+                tracker.map_revision() == Some(map.revision()) && !tracker.is_dirty()
+            };
+            if !synced {
+                let mut tracker = self.island_tracker.borrow_mut();
+                if tracker.map_revision() == Some(map.revision()) {
+                    tracker.refresh();
+                } else {
+                    let (rows, cols) = (map.rows(), map.cols());
+                    let walkable = (0..rows * cols)
+                        .map(|index| self.is_walkable_at(map, (index / cols) as i32, (index % cols) as i32))
+                        .collect();
+                    tracker.update(rows, cols, walkable);
+                    tracker.set_map_revision(Some(map.revision()));
+                }
+            }
+            self.island_tracker.borrow()
         }
-
-        /// Implementation of the Depth-First Search algorithm
+        /// Returns the islands of the known map
         ///
         /// # Arguments
         ///
         /// * `map` - the known world
-        /// * `row` - the row of the coordinate that we are analyzing
-        /// * `col` - the column of the coordinate that we are analyzing
-        /// * `visited` - a matrix that keeps track of the visited Coordinates
-        /// * `island_cells` - the vector containing the cells that are part of an island
-        fn dfs(&self, map: &Vec<Vec<Tile>>, row: i32, col: i32, visited: &mut Vec<Vec<bool>>, island_cells: &mut Vec<(i32, i32)>) {
-            let directions = vec![(0, 1), (0, -1), (1, 0), (-1, 0)];
-
-            visited[row as usize][col as usize] = true;
-            island_cells.push((row, col));
-
-            for (offset_row, offset_col) in directions {
-                let new_row = row + offset_row;
-                let new_col = col + offset_col;
-                if self.is_valid_move(map, new_row, new_col, visited) {
-                    self.dfs(map, new_row, new_col, visited, island_cells);
-                }
-            }
+        ///
+        /// # Returns
+        ///
+        /// A vector of islands, ordered by their first tile from the top left corner
+        ///
+        /// # Notes
+        ///
+        /// The islands are kept by the IslandTracker, so only the tiles that changed since the last call are processed,
+        /// and they are shared with the tracker instead of being copied
+        pub fn get_islands(&self, map: &KnownMap) -> Arc<Vec<Vec<(i32, i32)>>> {
+            Arc::clone(self.sync_island_tracker(map).islands())
         }
-
-        /// Implementation of the Depth-First Search algorithm
+        /// Maps every walkable tile to the island it belongs to
         ///
         /// # Arguments
         ///
         /// * `map` - the known world
+        /// * `islands` - the islands returned by get_islands()
         ///
         /// # Returns
         ///
-        /// A vector of islands
-        pub fn get_islands(&self, map: &Vec<Vec<Tile>>) -> Vec<Vec<(i32, i32)>> {
-            let rows = map.len() as i32;
-            let cols = map[0].len() as i32;
-            let mut visited = vec![vec![false; cols as usize]; rows as usize];
-            let mut islands_cells = Vec::new();
-
-            for i in 0..rows {
-                for j in 0..cols {
-                    if self.is_walkable(&map[i as usize][j as usize].tile_type) && !visited[i as usize][j as usize] {
-                        let mut island_cells = Vec::new();
-                        self.dfs(map, i, j, &mut visited, &mut island_cells);
-                        islands_cells.push(island_cells);
-                    }
+        /// A matrix with the index of the island that owns each tile, None for the tiles that don't belong to an island
+        pub(crate) fn get_island_owners(&self, map: &KnownMap, islands: &[Vec<(i32, i32)>]) -> Vec<Vec<Option<usize>>> {
+            let mut owners = vec![vec![None; map.cols()]; map.rows()];
+            for (id, island) in islands.iter().enumerate() {
+                for &(row, col) in island {
+                    owners[row as usize][col as usize] = Some(id);
                 }
             }
-
-            islands_cells
+            owners
         }
-        /// Finds the closest island to the robot's location
+        /// Returns true if one of the tiles around the given one hasn't been discovered
         ///
         /// # Arguments
         ///
-        /// * `islands` - A vector containing all the islands represented as vectors of (i32,i32)
-        ///
-        /// # Returns
-        ///
-        /// An Option of Vec of tuples. The vector represents the closest island to the robot
-        pub fn get_closest_island_to_robot(&mut self, islands:  &mut Vec<Vec<(i32, i32)>>) -> Option<Vec<(i32, i32)>> {
-            let (robot_row, robot_col) = self.get_coordinates();
-
-            let robot_island = self.get_robot_island(&islands);
-
-            islands.retain(|island| island != &robot_island.clone().unwrap());
-
-            if let Some(_robot_island) = robot_island {
-                // finding the island with the coordinate that is closer to the robot
-                let closest_island = islands.iter().min_by_key(|island| {
-                    island.iter().map(|(row, col)| (row - robot_row as i32).abs() + (col - robot_col as i32).abs()).min().unwrap()
-                });
-
-                closest_island.cloned()
-            } else {
-                None
-            }
+        /// * `map` - the known world
+        /// * `row` - the row of the tile
+        /// * `col` - the column of the tile
+        pub(crate) fn is_next_to_unknown(&self, map: &KnownMap, row: i32, col: i32) -> bool {
+            [(0, 1), (0, -1), (1, 0), (-1, 0)].iter()
+                .any(|(offset_row, offset_col)| map.is_unknown(row + offset_row, col + offset_col))
         }
         /// Finds the closest distance between the robot's island and the target island
         ///
         /// # Arguments
         /// * `map` - the known world
         /// * `robot_island` - the island where the robot stands
-        /// * `target_island` - the closest island to the robot
+        /// * `target_island` - the island to connect to the robot's one
         ///
         /// # Returns
         ///
         /// An option of coordinates indicating the closest walkable tiles that would connect the two islands if there was a bridge
-        pub fn get_closest_points(&self, map: &Vec<Vec<Tile>>, robot_island: Vec<(i32, i32)>, target_island: Vec<(i32, i32)>) -> Option<((i32, i32), (i32, i32))> {
+        pub fn get_closest_points(&self, map: &KnownMap, robot_island: &[(i32, i32)], target_island: &[(i32, i32)]) -> Option<((i32, i32), (i32, i32))> {
 
             let mut closest_coords = None;
             let mut min_distance = i32::MAX;
 
-            for (target_row,target_col) in target_island {
-                for (row,col) in &robot_island {
+            for &(target_row,target_col) in target_island {
+                for (row,col) in robot_island {
                     // if the coordinates exist and the Tile is walkable then we check the distance between the target and all the robot's island coordinates
-                    if self.is_in_bounds(&map,*row,*col) && self.is_walkable(&map[*row as usize][*col as usize].tile_type) {
+                    if self.is_walkable_at(map, *row, *col) {
                         let distance = (target_row - row).abs() + (target_col - col).abs();
                         if distance < min_distance {
                             min_distance = distance;
@@ -139,13 +127,18 @@ pub mod island {
         ///
         /// # Returns
         ///
-        /// An option of Vec of coordinates, indicating the robot's island
-        pub fn get_robot_island(&mut self, islands:  &Vec<Vec<(i32, i32)>>) -> Option<Vec<(i32, i32)>>{
+        /// An option of the robot's island, borrowed from the given islands
+        pub fn get_robot_island<'a>(&self, islands: &'a [Vec<(i32, i32)>]) -> Option<&'a Vec<(i32, i32)>> {
             let (robot_row, robot_col) = self.get_coordinates();
+            let robot = (robot_row as i32, robot_col as i32);
 
+            // the tracker already knows the robot's island if the islands come from get_islands()
+            let id = self.island_tracker.try_borrow().ok().and_then(|tracker| tracker.island_of(robot));
+            if let Some(island) = id.and_then(|id| islands.get(id)).filter(|island| island.contains(&robot)) {
+                return Some(island);
+            }
             islands.iter()
-                    .find(|island| island.contains(&(robot_row as i32, robot_col as i32)))
-                    .cloned()
+                    .find(|island| island.contains(&robot))
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,153 @@
+pub mod island_info {
+    // std
+    use std::collections::HashMap;
+    use std::fmt::{Display, Formatter};
+
+    // MinerRobot
+    use crate::{KnownMap, MinerRobot};
+
+    // robotics lib
+    use robotics_lib::world::tile::{Content, TileType};
+
+    /// What the robot knows about an island
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct IslandInfo {
+        /// id that doesn't change while the island grows, see IslandTracker
+        pub id: usize,
+        /// index of the island in get_islands()
+        pub index: usize,
+        pub tiles: Vec<(i32, i32)>,
+        /// amount of tiles of the island
+        pub area: usize,
+        /// top left and bottom right corners of the smallest rectangle that contains the island
+        pub bounding_box: ((i32, i32), (i32, i32)),
+        /// average (row, column) of the tiles
+        pub centroid: (f64, f64),
+        /// tiles of the island next to a tile the robot can't walk on
+        pub coastline: Vec<(i32, i32)>,
+        /// amount of tiles with each kind of content
+        pub content: HashMap<Content, usize>,
+        /// rocks lying on the island
+        pub rocks: usize,
+        /// amount of discovered tiles of each type
+        pub tile_types: HashMap<TileType, usize>,
+        /// amount of tiles of the island next to a tile the robot hasn't discovered yet
+        pub frontier: usize
+    }
+
+    impl IslandInfo {
+        /// Returns true if the island borders on tiles that the robot hasn't discovered yet
+        pub fn touches_unexplored(&self) -> bool {
+            self.frontier > 0
+        }
+        /// Returns how many tiles of the island contain the given kind of content
+        pub fn content_count(&self, content: &Content) -> usize {
+            self.content.get(content).copied().unwrap_or(0)
+        }
+        /// Returns true if a tile belongs to the island
+        pub fn contains(&self, coordinates: &(i32, i32)) -> bool {
+            let ((min_row, min_col), (max_row, max_col)) = self.bounding_box;
+            (min_row..=max_row).contains(&coordinates.0)
+                && (min_col..=max_col).contains(&coordinates.1)
+                && self.tiles.contains(coordinates)
+        }
+    }
+
+    impl Display for IslandInfo {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Island #{}: {} tiles in {:?} -> {:?}, centroid ({:.1}, {:.1}), {} coast tiles, {} rocks{}",
+                   self.id, self.area, self.bounding_box.0, self.bounding_box.1, self.centroid.0, self.centroid.1,
+                   self.coastline.len(), self.rocks, if self.touches_unexplored() { ", unexplored borders" } else { "" })
+        }
+    }
+
+    impl MinerRobot {
+        /// Describes every island of the known map
+        ///
+        /// # Arguments
+        ///
+        /// * `map` - the known world
+        ///
+        /// # Returns
+        ///
+        /// The IslandInfo of each island, in the same order as get_islands()
+        pub fn get_island_infos(&self, map: &KnownMap) -> Vec<IslandInfo> {
+            let tracker = self.sync_island_tracker(map);
+            tracker.islands().iter()
+                .zip(tracker.island_ids())
+                .enumerate()
+                .map(|(index, (tiles, &id))| self.describe_island(map, index, id, tiles.clone()))
+                .collect()
+        }
+        /// Returns the IslandInfo of the island where the robot stands
+        ///
+        /// # Arguments
+        ///
+        /// * `islands` - the islands returned by get_island_infos()
+        pub fn get_robot_island_info<'a>(&self, islands: &'a [IslandInfo]) -> Option<&'a IslandInfo> {
+            let (robot_row, robot_col) = self.get_coordinates();
+            islands.iter().find(|island| island.contains(&(robot_row as i32, robot_col as i32)))
+        }
+        /// Collects the metadata of an island
+        ///
+        /// # Arguments
+        ///
+        /// * `map` - the known world
+        /// * `index` - the index of the island in get_islands()
+        /// * `id` - the stable id of the island
+        /// * `tiles` - the tiles of the island
+        fn describe_island(&self, map: &KnownMap, index: usize, id: usize, tiles: Vec<(i32, i32)>) -> IslandInfo {
+            let mut bounding_box = ((i32::MAX, i32::MAX), (i32::MIN, i32::MIN));
+            let mut sum = (0.0, 0.0);
+            let mut coastline = Vec::new();
+            let mut content = HashMap::new();
+            let mut rocks = 0;
+            let mut tile_types = HashMap::new();
+            let mut frontier = 0;
+
+            for &(row, col) in &tiles {
+                let ((min_row, min_col), (max_row, max_col)) = bounding_box;
+                bounding_box = ((min_row.min(row), min_col.min(col)), (max_row.max(row), max_col.max(col)));
+                sum = (sum.0 + row as f64, sum.1 + col as f64);
+
+                // with the Optimistic policy an island can contain tiles that haven't been discovered
+                if let Some(tile) = map.tile(row, col) {
+                    *tile_types.entry(tile.tile_type.clone()).or_insert(0) += 1;
+                    if tile.content != Content::None {
+                        *content.entry(tile.content.clone()).or_insert(0) += 1;
+                    }
+                    if let Content::Rock(quantity) = tile.content {
+                        rocks += quantity;
+                    }
+                }
+
+                // the edges of the map and the excluded tiles are not a coast
+                let on_coast = [(0, 1), (0, -1), (1, 0), (-1, 0)].iter().any(|(offset_row, offset_col)| {
+                    map.planning_type(row + offset_row, col + offset_col)
+                        .map_or(false, |tile_type| !self.is_walkable(&tile_type))
+                });
+                if on_coast {
+                    coastline.push((row, col));
+                }
+                if self.is_next_to_unknown(map, row, col) {
+                    frontier += 1;
+                }
+            }
+
+            let area = tiles.len();
+            IslandInfo {
+                id,
+                index,
+                tiles,
+                area,
+                bounding_box,
+                centroid: (sum.0 / area.max(1) as f64, sum.1 / area.max(1) as f64),
+                coastline,
+                content,
+                rocks,
+                tile_types,
+                frontier
+            }
+        }
+    }
+}
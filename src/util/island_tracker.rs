@@ -0,0 +1,257 @@
+pub mod island_tracker {
+    // std
+    use std::collections::{HashMap, HashSet};
+    use std::sync::Arc;
+
+    // MinerRobot
+    use crate::DisjointSet;
+
+    /// Keeps track of the islands of the known map while new tiles are discovered or paved
+    ///
+    /// # Notes
+    ///
+    /// The walkable tiles are grouped with a union-find structure:
+    /// - a tile that becomes walkable is joined to its walkable neighbours, without looking at the rest of the map
+    /// - a tile that stops being walkable can split an island, so only the tiles of that island are joined again
+    ///
+    /// The islands are grouped again by refresh(), which only touches the islands around the changed tiles,
+    /// the getters return the islands of the last refresh.
+    /// Every island gets an id that doesn't change while the island grows: when two islands merge the older id is kept,
+    /// when an island splits the older id goes to the first part
+    #[derive(Debug, Clone, Default)]
+    pub struct IslandTracker {
+        rows: usize,
+        cols: usize,
+        walkable: Vec<bool>,
+        sets: DisjointSet,
+        changed: Vec<usize>,
+        islands: Arc<Vec<Vec<(i32, i32)>>>,
+        ids: Vec<usize>,
+        positions: HashMap<usize, usize>,
+        tile_ids: Vec<Option<usize>>,
+        next_id: usize,
+        map_revision: Option<u64>
+    }
+
+    impl IslandTracker {
+        pub fn new() -> Self {
+            Self::default()
+        }
+        /// Brings the tracker up to date with a whole map and groups the islands again
+        ///
+        /// # Arguments
+        ///
+        /// * `rows` - the rows of the map
+        /// * `cols` - the columns of the map
+        /// * `walkable` - whether the robot can walk on each tile, row by row
+        ///
+        /// # Notes
+        ///
+        /// Only the tiles that differ from the tracked ones are applied, the islands are found from scratch
+        /// only when the size of the map changes
+        pub fn update(&mut self, rows: usize, cols: usize, walkable: Vec<bool>) {
+            self.map_revision = None;
+            if rows != self.rows || cols != self.cols {
+                self.resize(rows, cols);
+            }
+            for (index, &now) in walkable.iter().enumerate().take(rows * cols) {
+                self.set_tile(index, now);
+            }
+            self.refresh();
+        }
+        /// Updates a single tile, the islands are grouped again on the next refresh()
+        ///
+        /// # Arguments
+        ///
+        /// * `coordinates` - the coordinates of the tile, nothing happens if they are out of the map
+        /// * `walkable` - whether the robot can walk on the tile
+        pub fn set_walkable(&mut self, coordinates: (i32, i32), walkable: bool) {
+            if let Some(index) = self.index(coordinates) {
+                self.set_tile(index, walkable);
+            }
+        }
+        /// Groups the tiles that changed since the last refresh, together with the islands around them
+        pub fn refresh(&mut self) {
+            if self.changed.is_empty() {
+                return;
+            }
+            let changed = std::mem::take(&mut self.changed);
+
+            // the islands of the last refresh that the changed tiles can join or split
+            let mut affected = HashSet::new();
+            let mut rejoin = false;
+            for &index in &changed {
+                rejoin |= !self.walkable[index] || self.tile_ids[index].is_some();
+                affected.extend(self.tile_ids[index]);
+                for neighbour in self.neighbours(index) {
+                    affected.extend(self.tile_ids[neighbour]);
+                }
+            }
+            let mut tiles: Vec<usize> = affected.iter()
+                .flat_map(|id| self.islands[self.positions[id]].iter())
+                .map(|&(row, col)| row as usize * self.cols + col as usize)
+                .chain(changed.iter().copied())
+                .filter(|&index| self.walkable[index])
+                .collect();
+            tiles.sort_unstable();
+            tiles.dedup();
+            for &index in &changed {
+                self.tile_ids[index] = None;
+            }
+
+            // a removed tile may have split its island, so the union-find is rebuilt on the affected tiles only
+            if rejoin {
+                for &index in &tiles {
+                    self.sets.reset(index);
+                }
+                for &index in &tiles {
+                    for neighbour in self.neighbours(index) {
+                        if self.walkable[neighbour] {
+                            self.sets.union(index, neighbour);
+                        }
+                    }
+                }
+            }
+
+            // the tiles are sorted, so the parts are ordered by their first tile
+            let mut roots: HashMap<usize, usize> = HashMap::new();
+            let mut parts: Vec<Vec<usize>> = Vec::new();
+            for &index in &tiles {
+                let root = self.sets.find(index);
+                let part = *roots.entry(root).or_insert_with(|| {
+                    parts.push(Vec::new());
+                    parts.len() - 1
+                });
+                parts[part].push(index);
+            }
+
+            // every part inherits the oldest id among the ones its tiles had
+            let mut taken = HashSet::new();
+            let mut entries: Vec<(usize, Vec<(i32, i32)>)> = Vec::with_capacity(parts.len());
+            for part in parts {
+                let inherited = part.iter()
+                    .filter_map(|&index| self.tile_ids[index])
+                    .filter(|id| !taken.contains(id))
+                    .min();
+                let id = match inherited {
+                    Some(id) => id,
+                    None => {
+                        self.next_id += 1;
+                        self.next_id - 1
+                    }
+                };
+                taken.insert(id);
+                for &index in &part {
+                    self.tile_ids[index] = Some(id);
+                }
+                entries.push((id, part.into_iter().map(|index| self.coordinates(index)).collect()));
+            }
+
+            // the untouched islands are moved, not copied, unless someone still shares them
+            let mut islands = Arc::try_unwrap(std::mem::take(&mut self.islands)).unwrap_or_else(|shared| (*shared).clone());
+            entries.extend(self.ids.drain(..).zip(islands.drain(..)).filter(|(id, _)| !affected.contains(id)));
+            entries.sort_by_key(|(_, island)| island[0]);
+            let (ids, islands): (Vec<usize>, Vec<Vec<(i32, i32)>>) = entries.into_iter().unzip();
+            self.positions = ids.iter().enumerate().map(|(position, &id)| (id, position)).collect();
+            self.ids = ids;
+            self.islands = Arc::new(islands);
+        }
+        /// Returns true if some tiles changed since the last refresh()
+        pub fn is_dirty(&self) -> bool {
+            !self.changed.is_empty()
+        }
+        /// Returns the revision of the KnownMap the tracker follows, None if it follows no KnownMap
+        pub fn map_revision(&self) -> Option<u64> {
+            self.map_revision
+        }
+        /// Records that the tracker follows the given revision of a KnownMap
+        pub fn set_map_revision(&mut self, revision: Option<u64>) {
+            self.map_revision = revision;
+        }
+        /// Returns the islands, ordered by their first tile from the top left corner
+        ///
+        /// # Notes
+        ///
+        /// The islands are shared, so cloning the Arc doesn't copy them and the next refresh() doesn't change them
+        pub fn islands(&self) -> &Arc<Vec<Vec<(i32, i32)>>> {
+            &self.islands
+        }
+        /// Returns the stable ids of the islands, in the same order as islands()
+        pub fn island_ids(&self) -> &Vec<usize> {
+            &self.ids
+        }
+        /// Returns the index in islands() of the island that contains a tile
+        ///
+        /// # Arguments
+        ///
+        /// * `coordinates` - the coordinates of the tile
+        pub fn island_of(&self, coordinates: (i32, i32)) -> Option<usize> {
+            let id = self.tile_ids.get(self.index(coordinates)?).copied().flatten()?;
+            self.positions.get(&id).copied()
+        }
+        /// Returns true if two tiles are on the same island
+        pub fn same_island(&self, first: (i32, i32), second: (i32, i32)) -> bool {
+            match (self.island_of(first), self.island_of(second)) {
+                (Some(first), Some(second)) => first == second,
+                _ => false
+            }
+        }
+        /// Converts coordinates into the index of a tile, None if they are out of the map
+        fn index(&self, (row, col): (i32, i32)) -> Option<usize> {
+            if row < 0 || col < 0 || row as usize >= self.rows || col as usize >= self.cols {
+                return None;
+            }
+            Some(row as usize * self.cols + col as usize)
+        }
+        /// Converts the index of a tile into its coordinates
+        fn coordinates(&self, index: usize) -> (i32, i32) {
+            ((index / self.cols) as i32, (index % self.cols) as i32)
+        }
+        /// Forgets every tile, the ids of the old tiles mean nothing on a map with a different size
+        fn resize(&mut self, rows: usize, cols: usize) {
+            self.rows = rows;
+            self.cols = cols;
+            self.walkable = vec![false; rows * cols];
+            self.sets = DisjointSet::new(rows * cols);
+            self.changed.clear();
+            self.islands = Arc::default();
+            self.ids.clear();
+            self.positions.clear();
+            self.tile_ids = vec![None; rows * cols];
+        }
+        /// Changes a tile, a tile that becomes walkable is joined to the islands around it
+        fn set_tile(&mut self, index: usize, walkable: bool) {
+            if self.walkable[index] == walkable {
+                return;
+            }
+            self.walkable[index] = walkable;
+            if walkable {
+                self.sets.reset(index);
+                for neighbour in self.neighbours(index) {
+                    if self.walkable[neighbour] {
+                        self.sets.union(index, neighbour);
+                    }
+                }
+            }
+            self.changed.push(index);
+        }
+        /// Returns the indices of the tiles above, below, on the left and on the right of a tile
+        fn neighbours(&self, index: usize) -> Vec<usize> {
+            let (row, col) = (index / self.cols, index % self.cols);
+            let mut neighbours = Vec::with_capacity(4);
+            if row > 0 {
+                neighbours.push(index - self.cols);
+            }
+            if row + 1 < self.rows {
+                neighbours.push(index + self.cols);
+            }
+            if col > 0 {
+                neighbours.push(index - 1);
+            }
+            if col + 1 < self.cols {
+                neighbours.push(index + 1);
+            }
+            neighbours
+        }
+    }
+}
@@ -0,0 +1,216 @@
+pub mod known_map {
+    // std
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    // MinerRobot
+    use crate::MinerRobot;
+
+    // robotics lib
+    use robotics_lib::world::tile::{Tile, TileType};
+
+    // serde
+    use serde::{Deserialize, Serialize};
+
+    // source of the revisions of the known maps, shared so that two different maps never get the same revision
+    static NEXT_REVISION: AtomicU64 = AtomicU64::new(1);
+
+    fn next_revision() -> u64 {
+        NEXT_REVISION.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// A tile of the known map
+    #[derive(Debug, Clone)]
+    pub enum KnownCell {
+        /// the robot hasn't discovered the tile yet
+        Unknown,
+        Known(Tile)
+    }
+
+    impl KnownCell {
+        /// Returns the tile, None if it hasn't been discovered
+        pub fn tile(&self) -> Option<&Tile> {
+            match self {
+                KnownCell::Unknown => None,
+                KnownCell::Known(tile) => Some(tile)
+            }
+        }
+        pub fn is_known(&self) -> bool {
+            matches!(self, KnownCell::Known(_))
+        }
+    }
+
+    impl From<Option<Tile>> for KnownCell {
+        fn from(tile: Option<Tile>) -> Self {
+            match tile {
+                Some(tile) => KnownCell::Known(tile),
+                None => KnownCell::Unknown
+            }
+        }
+    }
+
+    /// How the planners treat the tiles the robot hasn't discovered yet
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum UnknownPolicy {
+        /// the unknown tiles are deep water, so they can be paved but never walked on
+        #[default]
+        Pessimistic,
+        /// the unknown tiles are grass, so islands can extend into them
+        Optimistic,
+        /// the unknown tiles can be neither walked on nor paved
+        Excluded
+    }
+
+    /// The world as known by the robot, together with the way the planners read its unknown tiles
+    ///
+    /// # Notes
+    ///
+    /// The island, bridge and movement code never read the cells directly, they ask planning_type() instead,
+    /// so that the same map can be planned on with every UnknownPolicy.
+    ///
+    /// Every change gives the map a new revision, while a clone keeps the revision of the original,
+    /// so the IslandTracker can tell whether it already follows a map
+    #[derive(Debug, Clone, Default)]
+    pub struct KnownMap {
+        cells: Vec<Vec<KnownCell>>,
+        policy: UnknownPolicy,
+        revision: u64
+    }
+
+    impl KnownMap {
+        /// Creates a known map with the Pessimistic policy
+        ///
+        /// # Arguments
+        ///
+        /// * `cells` - the cells of the map, one vector per row
+        pub fn new(cells: Vec<Vec<KnownCell>>) -> Self {
+            KnownMap { cells, policy: UnknownPolicy::default(), revision: next_revision() }
+        }
+        /// Creates a known map from the map returned by robot_map()
+        pub fn from_robot_map(map: Vec<Vec<Option<Tile>>>) -> Self {
+            KnownMap::new(map.into_iter()
+                .map(|row| row.into_iter().map(KnownCell::from).collect())
+                .collect())
+        }
+        pub fn with_policy(mut self, policy: UnknownPolicy) -> Self {
+            self.policy = policy;
+            self.revision = next_revision();
+            self
+        }
+        /// Returns the revision of the map, it changes every time the map changes
+        pub fn revision(&self) -> u64 {
+            self.revision
+        }
+        pub fn policy(&self) -> UnknownPolicy {
+            self.policy
+        }
+        pub fn rows(&self) -> usize {
+            self.cells.len()
+        }
+        pub fn cols(&self) -> usize {
+            self.cells.first().map(|row| row.len()).unwrap_or(0)
+        }
+        /// Returns true if the coordinates are within the map
+        pub fn in_bounds(&self, row: i32, col: i32) -> bool {
+            row >= 0 && col >= 0 && (row as usize) < self.rows() && (col as usize) < self.cols()
+        }
+        /// Returns the cell at the given coordinates, None if they are out of the map
+        pub fn cell(&self, row: i32, col: i32) -> Option<&KnownCell> {
+            if !self.in_bounds(row, col) {
+                return None;
+            }
+            self.cells[row as usize].get(col as usize)
+        }
+        /// Returns the tile at the given coordinates, None if they are out of the map or the tile is unknown
+        pub fn tile(&self, row: i32, col: i32) -> Option<&Tile> {
+            self.cell(row, col).and_then(|cell| cell.tile())
+        }
+        /// Returns true if the coordinates are within the map and the tile hasn't been discovered
+        pub fn is_unknown(&self, row: i32, col: i32) -> bool {
+            matches!(self.cell(row, col), Some(KnownCell::Unknown))
+        }
+        /// Replaces a cell, nothing happens if the coordinates are out of the map
+        ///
+        /// # Arguments
+        ///
+        /// * `(row, col)` - the coordinates of the cell
+        /// * `cell` - the new cell
+        pub fn set(&mut self, (row, col): (usize, usize), cell: KnownCell) {
+            if let Some(cached) = self.cells.get_mut(row).and_then(|cells| cells.get_mut(col)) {
+                *cached = cell;
+                self.revision = next_revision();
+            }
+        }
+        /// Returns the tile type the planners use for a tile
+        ///
+        /// # Arguments
+        ///
+        /// * `row` - the row of the tile
+        /// * `col` - the column of the tile
+        ///
+        /// # Returns
+        ///
+        /// The type of a known tile, the type given by the policy for an unknown one,
+        /// None if the coordinates are out of the map or the unknown tiles are excluded
+        pub fn planning_type(&self, row: i32, col: i32) -> Option<TileType> {
+            match self.cell(row, col)? {
+                KnownCell::Known(tile) => Some(tile.tile_type.clone()),
+                KnownCell::Unknown => match self.policy {
+                    UnknownPolicy::Pessimistic => Some(TileType::DeepWater),
+                    UnknownPolicy::Optimistic => Some(TileType::Grass),
+                    UnknownPolicy::Excluded => None
+                }
+            }
+        }
+        /// Returns how many tiles haven't been discovered
+        pub fn unknown_count(&self) -> usize {
+            self.cells.iter().flatten().filter(|cell| !cell.is_known()).count()
+        }
+        /// Converts the map back into the format returned by robot_map(), used by the tools that need it
+        pub fn to_robot_map(&self) -> Vec<Vec<Option<Tile>>> {
+            self.cells.iter()
+                .map(|row| row.iter().map(|cell| cell.tile().cloned()).collect())
+                .collect()
+        }
+    }
+
+    /// Every tile of the map is known, used for the maps that are fully visible such as the AsciiWorld ones
+    impl From<Vec<Vec<Tile>>> for KnownMap {
+        fn from(map: Vec<Vec<Tile>>) -> Self {
+            KnownMap::new(map.into_iter()
+                .map(|row| row.into_iter().map(KnownCell::Known).collect())
+                .collect())
+        }
+    }
+
+    impl MinerRobot {
+        /// Checks if the robot can walk on a tile of the known map
+        ///
+        /// # Arguments
+        ///
+        /// * `map` - the known world
+        /// * `row` - the row of the tile
+        /// * `col` - the column of the tile
+        ///
+        /// # Returns
+        ///
+        /// false if the tile is out of the map or excluded by the UnknownPolicy
+        pub fn is_walkable_at(&self, map: &KnownMap, row: i32, col: i32) -> bool {
+            map.planning_type(row, col).map_or(false, |tile_type| self.is_walkable(&tile_type))
+        }
+        /// Checks if the robot can pave a tile of the known map
+        ///
+        /// # Arguments
+        ///
+        /// * `map` - the known world
+        /// * `row` - the row of the tile
+        /// * `col` - the column of the tile
+        ///
+        /// # Returns
+        ///
+        /// false if the tile is out of the map or excluded by the UnknownPolicy
+        pub fn is_pavable_at(&self, map: &KnownMap, row: i32, col: i32) -> bool {
+            map.planning_type(row, col).map_or(false, |tile_type| self.is_pavable(&tile_type))
+        }
+    }
+}
@@ -6,3 +6,22 @@ pub mod island;
 pub mod bridge;
 pub mod discovery;
 pub mod movement;
+pub mod config;
+pub mod error;
+pub mod state;
+pub mod generator;
+pub mod ascii;
+pub mod scenario;
+pub mod sound;
+pub mod energy;
+pub mod instrumented;
+pub mod plan;
+pub mod network;
+pub mod cost;
+pub mod target;
+pub mod budget;
+pub mod island_tracker;
+pub mod island_info;
+pub mod known_map;
+pub mod graph;
+pub mod disjoint_set;
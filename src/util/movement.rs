@@ -1,50 +1,224 @@
 pub mod movement {
+    // std
+    use std::sync::Arc;
+
     // MinerRobot
-    use crate::MinerRobot;
+    use crate::{KnownMap, MinerError, MinerRobot};
 
     // robotics lib
     use robotics_lib::interface::{Direction, go};
-    use robotics_lib::world::tile::{Tile, TileType};
+    use robotics_lib::utils::LibError;
+    use robotics_lib::world::tile::TileType;
     use robotics_lib::world::World;
+
+    // amount of movement failures kept in the robot's log
+    const MOVEMENT_LOG_SIZE: usize = 50;
+
+    /// A failed attempt to move the robot
+    #[derive(Debug)]
+    pub struct MovementFailure {
+        pub from: (usize,usize),
+        pub direction: Direction,
+        pub error: LibError
+    }
+
     impl MinerRobot {
         /// Moves the robot to the given coordinates
         ///
         /// # Arguments
         ///
         /// * `world` - the world
-        /// * `map` - the known world
         /// * `coordinates` - the target coordinates
-        pub fn move_to_coords(&mut self, world: &mut World, map: &Vec<Vec<Tile>>, coordinates: (i32,i32)) {
+        pub fn move_to_coords(&mut self, world: &mut World, coordinates: (i32,i32)) -> Result<(), MinerError> {
             let (row,col) = coordinates;
-
-            // retrieving the action vector, which will indicate the robot the directions to go to
-            let action_vec = match self.lssf.get_action_vec(row as usize,col as usize){
-                Ok(vec) => vec,
-                Err(e) => {
-                    self.catch_lib_error(world,e);
-                    return ();
+            self.execute_path(world, (row as usize, col as usize), false, true)?;
+            Ok(())
+        }
+        /// Moves the robot along the path that leads to the target, replanning when a move fails
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        /// * `target` - the target coordinates
+        /// * `stop_before` - whether the robot has to stop on the tile before the target
+        /// * `skip_unwalkable` - whether the steps that lead to a non walkable tile are skipped
+        ///
+        /// # Returns
+        ///
+        /// The direction of the last step that wasn't taken if `stop_before` is true,
+        /// MinerError::MovementFailed if the robot couldn't reach the target
+        ///
+        /// # Notes
+        ///
+        /// Unless `skip_unwalkable` is set, the path is rejected with MinerError::ForbiddenPath
+        /// if it crosses a tile the TileCostModel doesn't allow the robot to walk on, since Lssf doesn't know the model.
+        ///
+        /// Every time go() fails:
+        /// - the failure gets recorded and, if needed, the energy gets recharged
+        /// - the known map gets refreshed
+        /// - the remaining path gets recomputed from the robot's current coordinates
+        ///
+        /// The robot gives up after `max_move_retries` failures
+        pub fn execute_path(&mut self, world: &mut World, target: (usize,usize), stop_before: bool, skip_unwalkable: bool) -> Result<Option<Direction>, MinerError> {
+            let mut attempts = 0;
+            loop {
+                // refreshing the known map and computing the path from the robot's coordinates
+                let map = self.refresh_known_map(world)?;
+                let action_vec = self.lssf.get_action_vec(target.0, target.1)?;
+                let mut directions: Vec<Direction> = action_vec.iter().map(|action| self.action_to_direction(action)).collect();
+                let last_direction = if stop_before { directions.pop() } else { None };
+                if !skip_unwalkable {
+                    if let Some(tile) = self.first_forbidden_tile(&map, self.get_coordinates(), &directions) {
+                        return Err(MinerError::ForbiddenPath { target, tile });
+                    }
                 }
-            };
 
-            // iterating through all the actions that will lead the robot to the target coordinates
-            for action in action_vec {
-                let (robot_row, robot_col) = self.get_coordinates();
-                let direction = self.action_to_direction(&action);
+                match self.follow_directions(world, &map, &directions, skip_unwalkable) {
+                    Ok(()) => return Ok(last_direction),
+                    Err(failure) => {
+                        attempts += 1;
+                        self.record_movement_failure(world, failure);
+                        if attempts > self.config.max_move_retries {
+                            return Err(MinerError::MovementFailed { target, attempts });
+                        }
+                    }
+                }
+            }
+        }
+        /// Returns the first tile of a path that the robot isn't allowed to walk on
+        ///
+        /// # Arguments
+        ///
+        /// * `map` - the known world
+        /// * `from` - the coordinates where the path starts
+        /// * `directions` - the directions of the path
+        ///
+        /// # Returns
+        ///
+        /// The coordinates of the tile, None if the robot can walk on the whole path
+        pub fn first_forbidden_tile(&self, map: &KnownMap, from: (usize,usize), directions: &[Direction]) -> Option<(i32,i32)> {
+            let (mut row, mut col) = (from.0 as i32, from.1 as i32);
+            for direction in directions {
+                let (row_offset, col_offset) = self.direction_to_offset(direction);
+                row += row_offset;
+                col += col_offset;
+                if !self.is_walkable_at(map, row, col) {
+                    return Some((row, col));
+                }
+            }
+            None
+        }
+        /// Moves the robot one tile towards the given direction, retrying if the move fails
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        /// * `direction` - the direction of the move
+        ///
+        /// # Returns
+        ///
+        /// MinerError::MovementFailed if the robot couldn't move after `max_move_retries` attempts
+        pub fn step_with_retries(&mut self, world: &mut World, direction: &Direction) -> Result<(), MinerError> {
+            let mut attempts = 0;
+            loop {
+                match self.step(world, direction) {
+                    Ok(()) => return Ok(()),
+                    Err(failure) => {
+                        attempts += 1;
+                        let (row, col) = failure.from;
+                        self.record_movement_failure(world, failure);
+                        if attempts > self.config.max_move_retries {
+                            let (offset_row, offset_col) = self.direction_to_offset(direction);
+                            let target = ((row as i32 + offset_row) as usize, (col as i32 + offset_col) as usize);
+                            return Err(MinerError::MovementFailed { target, attempts });
+                        }
+                        // the cache may be the reason why the move failed
+                        self.invalidate_known_map();
+                        self.refresh_known_map(world)?;
+                    }
+                }
+            }
+        }
+        /// Moves the robot following the given directions
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        /// * `map` - the known world
+        /// * `directions` - the directions to follow
+        /// * `skip_unwalkable` - whether the steps that lead to a non walkable tile are skipped
+        ///
+        /// # Returns
+        ///
+        /// The MovementFailure of the first move that failed
+        fn follow_directions(&mut self, world: &mut World, map: &KnownMap, directions: &[Direction], skip_unwalkable: bool) -> Result<(), MovementFailure> {
+            for direction in directions {
+                if skip_unwalkable {
+                    let (robot_row, robot_col) = self.get_coordinates();
 
-                // calculating the new row and column
-                let (row_offset, col_offset) = self.direction_to_offset(&direction);
-                let target_row = robot_row as i32 + row_offset;
-                let target_col = robot_col as i32 + col_offset;
+                    // calculating the new row and column
+                    let (row_offset, col_offset) = self.direction_to_offset(direction);
+                    let target_row = robot_row as i32 + row_offset;
+                    let target_col = robot_col as i32 + col_offset;
 
-                // checking if the coordinates are within bounds and if the tile_type is walkable
-                if self.is_in_bounds(&map,target_row,target_col) && self.is_walkable(&map[target_row as usize][target_col as usize].tile_type) {
-                    let msg = format!("Failed to move {:?}", direction);
-                    self.manage_energy(world);
-                    go(self,world,direction.clone()).expect(msg.as_str());
+                    // checking if the coordinates are within bounds and if the tile_type is walkable
+                    if !self.is_walkable_at(map, target_row, target_col) {
+                        continue;
+                    }
                 }
+                self.step(world, direction)?;
+            }
+            Ok(())
+        }
+        /// Moves the robot one tile towards the given direction
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        /// * `direction` - the direction of the move
+        fn step(&mut self, world: &mut World, direction: &Direction) -> Result<(), MovementFailure> {
+            let from = self.get_coordinates();
+            self.manage_energy(world);
+            match go(self, world, direction.clone()) {
+                Ok((view, coordinates)) => {
+                    // the tiles around the new position have just been discovered
+                    self.cache_known_view(&view, coordinates);
+                    Ok(())
+                },
+                Err(error) => Err(MovementFailure { from, direction: direction.clone(), error })
+            }
+        }
+        /// Stores a movement failure in the robot's log
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        /// * `failure` - the failed move
+        fn record_movement_failure(&mut self, world: &mut World, failure: MovementFailure) {
+            if matches!(failure.error, LibError::NotEnoughEnergy) {
+                self.recharge_energy(world);
+            }
+            self.movement_failures.push_back(failure);
+            if self.movement_failures.len() > MOVEMENT_LOG_SIZE {
+                self.movement_failures.pop_front();
             }
         }
-        /// Checks if a tile is walkable or not
+        /// Refreshes the Lssf map and cost from the robot's coordinates
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        ///
+        /// # Returns
+        ///
+        /// The refreshed known world
+        fn refresh_known_map(&mut self, world: &World) -> Result<Arc<KnownMap>, MinerError> {
+            let map = Arc::clone(self.sync_known_map(world));
+            let (row, col) = self.get_coordinates();
+            self.update_lssf_map_and_cost(&map.to_robot_map(), row, col)?;
+            Ok(map)
+        }
+        /// Checks if a tile is walkable or not, following the robot's TileCostModel
         ///
         /// # Arguments
         ///
@@ -54,12 +228,7 @@ pub mod movement {
         ///
         /// A bool stating whether the tile is walkable or not
         pub fn is_walkable(&self, tile_type: &TileType) -> bool {
-            match tile_type {
-                TileType::DeepWater => false,
-                TileType::Lava => false,
-                TileType::ShallowWater => false,
-                _ => true
-            }
+            self.cost_model.is_walkable(tile_type)
         }
         /// Converts the direction into an offset
         ///
@@ -78,6 +247,24 @@ pub mod movement {
                 Direction::Right => (0,1),
             }
         }
+        /// Converts an offset between two adjacent tiles into a direction
+        ///
+        /// # Arguments
+        ///
+        /// * `offset` - the offset as (row, col)
+        ///
+        /// # Returns
+        ///
+        /// The direction, or None if the tiles are not adjacent
+        pub fn offset_to_direction(&self, offset: (i32,i32)) -> Option<Direction> {
+            match offset {
+                (-1,0) => Some(Direction::Up),
+                (0,-1) => Some(Direction::Left),
+                (1,0) => Some(Direction::Down),
+                (0,1) => Some(Direction::Right),
+                _ => None
+            }
+        }
         /// Checks if the row value and the column value are within the matrix bounds
         ///
         /// # Arguments
@@ -89,10 +276,8 @@ pub mod movement {
         /// # Returns
         ///
         /// A bool representing whether the values are in bounds or not
-        pub fn is_in_bounds(&self, map: &Vec<Vec<Tile>>, row: i32, col: i32) -> bool {
-            let rows = map.len() as i32;
-            let cols = map[0].len() as i32;
-            row >= 0 && col >= 0 && row < rows && col < cols
+        pub fn is_in_bounds(&self, map: &KnownMap, row: i32, col: i32) -> bool {
+            map.in_bounds(row, col)
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,146 @@
+pub mod network {
+    // std
+    use std::collections::HashMap;
+
+    // MinerRobot
+    use crate::{BridgePlan, DisjointSet, KnownMap, MinerRobot};
+
+    // serde
+    use serde::{Deserialize, Serialize};
+
+    /// The bridges that connect every island with the fewest rocks
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct BridgeNetwork {
+        /// amount of islands in the network
+        pub islands: usize,
+        /// the bridges of the minimum spanning tree, from the cheapest to the most expensive
+        pub bridges: Vec<BridgePlan>,
+        /// rocks needed to build every bridge
+        pub total_rocks: usize
+    }
+
+    impl BridgeNetwork {
+        /// Orders the bridges so that they can be built one after the other starting from an island
+        ///
+        /// # Arguments
+        ///
+        /// * `island` - the index of the island where the robot stands
+        ///
+        /// # Returns
+        ///
+        /// The bridges in the order in which they have to be built
+        ///
+        /// # Notes
+        ///
+        /// Every bridge starts from an island that is already connected to the robot's island,
+        /// and among the bridges that can be built next the cheapest one is chosen,
+        /// so that the robot needs to gather as few rocks as possible before each bridge
+        pub fn schedule(&self, island: usize) -> Vec<BridgePlan> {
+            let mut connected = vec![false; self.islands];
+            if island >= self.islands {
+                return Vec::new();
+            }
+            connected[island] = true;
+
+            let mut pending: Vec<&BridgePlan> = self.bridges.iter().collect();
+            let mut scheduled = Vec::new();
+            loop {
+                // the bridges are sorted by cost, so the first one that touches the connected islands is the cheapest
+                let next = pending.iter().position(|plan| connected[plan.source_island] != connected[plan.target_island]);
+                let plan = match next {
+                    Some(index) => pending.remove(index),
+                    None => break
+                };
+                let plan = if connected[plan.source_island] { plan.clone() } else { plan.reversed() };
+                connected[plan.target_island] = true;
+                scheduled.push(plan);
+            }
+            scheduled
+        }
+        /// Returns the next bridge of the schedule if the rocks are enough to build it
+        ///
+        /// # Arguments
+        ///
+        /// * `island` - the index of the island where the robot stands
+        /// * `rocks` - the rocks the robot has
+        pub fn next_affordable(&self, island: usize, rocks: usize) -> Option<BridgePlan> {
+            self.schedule(island).into_iter().next().filter(|plan| plan.total_rocks <= rocks)
+        }
+    }
+
+    impl BridgePlan {
+        /// Returns the same bridge built from the other side
+        pub fn reversed(&self) -> BridgePlan {
+            let mut path = self.path.clone();
+            path.reverse();
+            let mut tiles = self.tiles.clone();
+            tiles.reverse();
+            BridgePlan {
+                start: self.end,
+                end: self.start,
+                path,
+                tiles,
+                total_rocks: self.total_rocks,
+                estimated_energy: self.estimated_energy,
+                source_island: self.target_island,
+                target_island: self.source_island
+            }
+        }
+    }
+
+    impl MinerRobot {
+        /// Plans the network of bridges that connects every known island with the fewest rocks
+        ///
+        /// # Arguments
+        ///
+        /// * `map` - the known world
+        ///
+        /// # Returns
+        ///
+        /// The BridgeNetwork, made of the bridges of the minimum spanning tree over the islands
+        ///
+        /// # Notes
+        ///
+        /// The cheapest bridge between every pair of neighbouring islands is an edge weighted with its rocks,
+        /// then the Kruskal algorithm picks the edges of the minimum spanning tree.
+        /// Islands that cannot be reached are left out of the network.
+        pub fn plan_bridge_network(&self, map: &KnownMap) -> BridgeNetwork {
+            let islands = self.get_islands(map);
+
+            let owners = self.get_island_owners(map, &islands);
+
+            // keeping only the cheapest bridge for each pair of islands
+            let mut edges: HashMap<(usize, usize), BridgePlan> = HashMap::new();
+            for (source, island) in islands.iter().enumerate() {
+                for (target, path, rocks) in self.get_cheapest_bridges(map, island, &owners) {
+                    let plan = self.build_plan(map, path, rocks, source, target);
+                    let key = (source.min(target), source.max(target));
+                    let cheaper = match edges.get(&key) {
+                        Some(edge) => (plan.total_rocks, plan.len()) < (edge.total_rocks, edge.len()),
+                        None => true
+                    };
+                    if cheaper {
+                        edges.insert(key, plan);
+                    }
+                }
+            }
+            let mut edges: Vec<BridgePlan> = edges.into_values().collect();
+            edges.sort_by_key(|plan| (plan.total_rocks, plan.len(), plan.source_island.min(plan.target_island), plan.source_island.max(plan.target_island)));
+
+            // Kruskal: an edge is taken if it connects two different components
+            let mut components = DisjointSet::new(islands.len());
+            let mut bridges = Vec::new();
+            for plan in edges {
+                if components.union(plan.source_island, plan.target_island) {
+                    bridges.push(plan);
+                }
+            }
+
+            BridgeNetwork {
+                islands: islands.len(),
+                total_rocks: bridges.iter().map(|plan| plan.total_rocks).sum(),
+                bridges
+            }
+        }
+    }
+}
@@ -1,19 +1,17 @@
 pub mod path_find {
     // MinerRobot
-    use crate::{MinerRobot, RobotState, SCAN_INCREASE};
+    use crate::{MinerError, MinerRobot};
 
     // robotics lib
-    use robotics_lib::interface::{destroy, go, Direction};
+    use robotics_lib::interface::{destroy, Direction};
     use robotics_lib::world::{tile::Content, World};
 
     // tools
     use sense_and_find_by_rustafariani::Action;
     use bob_lib::tracker::*;
-    use colored::Colorize;
-    use OwnerSheeps_Sound_Tool::functions::destroying_sound::play_sound_mining_rock;
 
-    const RANGE: usize = 2;
-    const DIRECTION: Direction = Direction::Up;
+    // sounds
+    use crate::util::sound::sound::SoundEvent;
 
     impl MinerRobot {
         /// Moves the robot to a target tile and collects the specified Content present in that tile
@@ -23,41 +21,34 @@ pub mod path_find {
         /// * `world` - the world
         /// * `content` - the content that we want to collect
         ///
-        /// # Notes
+        /// # Returns
         ///
-        /// If the coordinates are the same after calling the move it means that the content vector is empty.
-        /// The way that this issue is handled is by:
-        /// - increasing the distance
-        /// - setting the scanned value to false in order to call the discover once again
-        /// - getting all the content around the robot
-        /// - trying to collect rocks
-        /// we increase the distance and reset the scanned value to false in order to call the discover once again
-        pub fn move_and_collect_content(&mut self, world: &mut World, content: Content) {
+        /// MinerError::NoContentFound if there is no reachable tile containing the content
+        pub fn move_and_collect_content(&mut self, world: &mut World, content: Content) -> Result<(), MinerError> {
 
             // getting the vector that contains the cost to reach tiles from the robot's coordinates
-            let vec = self.get_cost_vector_to_content(world, content);
+            let vec = self.get_cost_vector_to_content(world, content)?;
 
             // moving the robot on the target tile and collecting the content
-            let (row,col) = self.get_coordinates();
-            self.state = RobotState::CollectingRocks;
-            self.move_to_tile_destroy_content(world, vec);
-            let (new_row,new_col) = self.get_coordinates();
-
-            if (row,col) == (new_row,new_col) {
-                // increasing the scan of the spyglass and resetting the world_scanned value in order to scan it again
-                println!("{}", "Increased scan".green());
-                self.scan_distance += SCAN_INCREASE;
-                self.world_scanned = false;
-
-                // setting conditions to use the collect_rocks method and to end the game
-                self.collect_all(world,RANGE);
-                if self.scan_distance > (SCAN_INCREASE * 4) {
-                    self.game_is_over();
-                    println!("{:?}", self);
-                } else if self.scan_distance > (SCAN_INCREASE * 3) {
-                    self.collect_rocks_inline(world,DIRECTION);
-                }
-            }
+            self.move_to_tile_destroy_content(world, vec)
+        }
+        /// Moves the robot next to the closest tile containing the specified Content
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        /// * `content` - the content that we want to collect
+        ///
+        /// # Returns
+        ///
+        /// MinerError::NoContentFound if there is no reachable tile containing the content
+        ///
+        /// # Notes
+        ///
+        /// The direction of the tile is stored, so that destroy_facing_content() can collect the content later
+        pub fn move_next_to_content(&mut self, world: &mut World, content: Content) -> Result<(), MinerError> {
+            let vec = self.get_cost_vector_to_content(world, content)?;
+            self.move_next_to_tile(world, vec)
         }
         /// Generates and returns the vector that associates coordinates containing Content, with the cost to reach them
         ///
@@ -71,14 +62,14 @@ pub mod path_find {
         /// A vector of tuples:
         /// - the first element represents the cost to reach the tile
         /// - the second element represents the coordinates of the tile
-        pub fn get_cost_vector_to_content(&mut self, world: &mut World, content: Content) -> Vec<(usize,(usize,usize))>{
+        pub fn get_cost_vector_to_content(&mut self, world: &mut World, content: Content) -> Result<Vec<(usize,(usize,usize))>, MinerError> {
             let mut cost_vector: Vec<(usize,(usize,usize))> = Vec::new();
 
             let map = self.get_map_option(world);
             let (x,y) = self.get_coordinates();
 
             // updating both map and costs
-            self.update_lssf_map_and_cost(world, &map, x, y);
+            self.update_lssf_map_and_cost(&map, x, y)?;
 
             // getting the vector that contains all the coordinates of tiles that contain a specific content
             let content_vec = self.get_tiles_by_content(world,content);
@@ -97,7 +88,7 @@ pub mod path_find {
 
             // we order the cost vector so that the first element is the one with the lesser cost
             cost_vector.sort();
-            cost_vector
+            Ok(cost_vector)
         }
 
         /// Moves towards a target tile and destroys its content
@@ -110,45 +101,52 @@ pub mod path_find {
         /// # Notes
         ///
         /// The robot moves until it reaches the tile near the target, and then it destroys the target's content
-        pub fn move_to_tile_destroy_content(&mut self, world: &mut World, vec: Vec<(usize, (usize, usize))>) {
-            let (_cost,(x,y));
-            // if the vector is not empty then we take the first element which is the one that costs less to go to
-            if vec.len() > 0 {
-                (_cost,(x,y)) = vec[0];
-            } else { // otherwise we return
-                println!("The content vector is empty");
-                return;
-            }
-
-            let action_vec = match self.lssf.get_action_vec(x,y){
-                Ok(vec) => vec,
-                Err(e) => {
-                    self.catch_lib_error(world,e);
-                    return ();
-                }
-            };
-
-            for (i,action) in action_vec.iter().enumerate() {
-                let direction = self.action_to_direction(action);
-                // calling the destroy if the robot is facing the tile containing Content
-                if i == action_vec.len() - 1 {
-                    match destroy(self, world, direction.clone()) {
-                        Ok(quantity) => {
-                            play_sound_mining_rock();
-                            // updating the rock count and the goal tracker
-                            self.update_rock_count();
-                            self.goal_tracker.update_manual(GoalType::GetItems,Some(Content::Rock(1)),quantity);
-                        }
-                        Err(e) => {
-                            self.catch_lib_error(world,e);
-                        }
-                    } ;
+        pub fn move_to_tile_destroy_content(&mut self, world: &mut World, vec: Vec<(usize, (usize, usize))>) -> Result<(), MinerError> {
+            self.move_next_to_tile(world, vec)?;
+            self.destroy_facing_content(world)
+        }
+        /// Moves the robot on the tile near the target and stores the direction the robot is facing
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        /// * `vec` - the vector of tuples (cost(row,col))
+        ///
+        /// # Notes
+        ///
+        /// The targets are tried from the one that costs less to go to,
+        /// skipping the ones whose path crosses a tile the TileCostModel forbids
+        pub(crate) fn move_next_to_tile(&mut self, world: &mut World, vec: Vec<(usize, (usize, usize))>) -> Result<(), MinerError> {
+            let mut forbidden = None;
+            for &(_cost,(x,y)) in &vec {
+                // moving the robot next to the target tile
+                match self.execute_path(world, (x,y), true, false) {
+                    Ok(direction) => {
+                        self.content_direction = direction;
+                        return Ok(());
+                    }
+                    Err(error @ MinerError::ForbiddenPath { .. }) => forbidden = Some(error),
+                    Err(error) => return Err(error)
                 }
-                // moving the robot to Direction and returning an error message in case of failure
-                let msg = format!("Failed to move {:?}", direction);
-                self.manage_energy(world);
-                go(self,world,direction.clone()).expect(msg.as_str());
             }
+            Err(forbidden.unwrap_or(MinerError::NoContentFound))
+        }
+        /// Destroys the content of the tile the robot is facing and moves on that tile
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        pub fn destroy_facing_content(&mut self, world: &mut World) -> Result<(), MinerError> {
+            if let Some(direction) = self.content_direction.take() {
+                // calling the destroy since the robot is facing the tile containing Content
+                let quantity = destroy(self, world, direction.clone())?;
+                self.sound.play(SoundEvent::MiningRock, world);
+                // updating the goal tracker, the rock count is kept in sync by handle_event()
+                self.goal_tracker.update_manual(GoalType::GetItems,Some(Content::Rock(1)),quantity);
+
+                self.step_with_retries(world, &direction)?;
+            }
+            Ok(())
         }
         /// Converts an action into a direction
         ///
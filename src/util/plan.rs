@@ -0,0 +1,101 @@
+pub mod plan {
+    // std
+    use std::fmt::{Display, Formatter};
+
+    // robotics lib
+    use robotics_lib::world::tile::TileType;
+
+    // serde
+    use serde::{Deserialize, Serialize};
+
+    /// A tile that has to be paved to build a bridge
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct PlannedTile {
+        pub coordinates: (i32, i32),
+        pub tile_type: TileType,
+        pub rocks: usize
+    }
+
+    /// A bridge that has been planned but not built yet
+    ///
+    /// # Notes
+    ///
+    /// The plan is computed on the known map without touching the world,
+    /// so it can be printed or serialized before the robot starts building
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct BridgePlan {
+        /// tile of the robot's island where the bridge starts
+        pub start: (i32, i32),
+        /// tile of the target island where the bridge ends
+        pub end: (i32, i32),
+        /// every tile the robot walks on, from start to end
+        pub path: Vec<(i32, i32)>,
+        /// the tiles of the path that have to be paved, in order
+        pub tiles: Vec<PlannedTile>,
+        /// rocks needed to pave every tile
+        pub total_rocks: usize,
+        /// energy needed to pave the tiles and walk from start to end
+        pub estimated_energy: usize,
+        /// index of the island where the bridge starts in get_islands()
+        pub source_island: usize,
+        /// index of the target island in get_islands()
+        pub target_island: usize
+    }
+
+    impl BridgePlan {
+        /// Returns the amount of steps from start to end
+        pub fn len(&self) -> usize {
+            self.path.len().saturating_sub(1)
+        }
+        /// Returns true if the start and the end of the bridge are the same tile
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+    }
+
+    /// A bridge that has been built only in part
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct BridgeProgress {
+        pub plan: BridgePlan,
+        /// index in the plan's path of the last tile the robot reached
+        pub reached: usize
+    }
+
+    impl BridgeProgress {
+        /// Returns the tile from which the construction has to resume
+        pub fn resume_tile(&self) -> (i32, i32) {
+            self.plan.path[self.reached]
+        }
+        /// Returns the planned tiles that still have to be paved, in order
+        pub fn remaining_tiles(&self) -> Vec<&PlannedTile> {
+            let remaining = &self.plan.path[self.reached + 1..];
+            self.plan.tiles.iter().filter(|tile| remaining.contains(&tile.coordinates)).collect()
+        }
+        /// Returns the rocks needed to pave the next tile
+        pub fn next_tile_rocks(&self) -> usize {
+            self.remaining_tiles().first().map(|tile| tile.rocks).unwrap_or(0)
+        }
+        /// Returns the rocks needed to finish the bridge
+        pub fn remaining_rocks(&self) -> usize {
+            self.remaining_tiles().iter().map(|tile| tile.rocks).sum()
+        }
+    }
+
+    impl Display for BridgeProgress {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            let paved = self.plan.tiles.len() - self.remaining_tiles().len();
+            write!(f, "{}/{} tiles paved towards {:?}, {} rocks left", paved, self.plan.tiles.len(), self.plan.end, self.remaining_rocks())
+        }
+    }
+
+    impl Display for BridgePlan {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            writeln!(f, "Bridge {:?} -> {:?} from island {} to island {}: {} rocks, ~{} energy, {} steps",
+                     self.start, self.end, self.source_island, self.target_island, self.total_rocks, self.estimated_energy, self.len())?;
+            for tile in &self.tiles {
+                writeln!(f, "  - {:?} {:?}: {} rocks", tile.coordinates, tile.tile_type, tile.rocks)?;
+            }
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,303 @@
+pub mod scenario {
+    // std
+    use std::collections::HashSet;
+    use std::sync::{Arc, Mutex};
+
+    // MinerRobot
+    use crate::{AsciiWorld, BridgePlan, FinishReason, IslandTracker, MemoryWorldGenerator, MinerConfig, MinerRobot, RobotState, SilentSound, SoundSink, TileCostModel};
+
+    // robotics lib
+    use robotics_lib::energy::Energy;
+    use robotics_lib::event::events::Event;
+    use robotics_lib::interface::robot_map;
+    use robotics_lib::runner::backpack::BackPack;
+    use robotics_lib::runner::{Runnable, Runner};
+    use robotics_lib::world::coordinates::Coordinate;
+    use robotics_lib::world::tile::{Content, Tile};
+    use robotics_lib::world::World;
+
+    // amount of ticks run when max_ticks is not set
+    const DEFAULT_MAX_TICKS: usize = 100;
+
+    /// A world fixture that drives the MinerRobot without a GUI
+    ///
+    /// # Notes
+    ///
+    /// The robot is silent unless a different SoundSink is given with sound()
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let result = Scenario::from_ascii(include_str!("scenarios/two_islands.txt")).max_ticks(200).run();
+    /// result.assert_rocks_at_least(1).assert_energy_never_below_min();
+    /// ```
+    pub struct Scenario {
+        generator: MemoryWorldGenerator,
+        config: MinerConfig,
+        max_ticks: usize,
+        sound: Box<dyn SoundSink>,
+        cost_model: Option<Box<dyn TileCostModel>>
+    }
+
+    impl Scenario {
+        /// Creates a new scenario given the world
+        pub fn new(generator: MemoryWorldGenerator) -> Self {
+            Self {
+                generator,
+                config: MinerConfig::default(),
+                max_ticks: DEFAULT_MAX_TICKS,
+                sound: Box::new(SilentSound),
+                cost_model: None
+            }
+        }
+        /// Creates a new scenario given an ASCII map
+        ///
+        /// # Panics
+        ///
+        /// Panics if the map is not valid, since scenarios are written by hand
+        pub fn from_ascii(text: &str) -> Self {
+            let world = AsciiWorld::parse(text).unwrap_or_else(|e| panic!("Invalid scenario map: {}", e));
+            let generator = world.into_generator().unwrap_or_else(|e| panic!("Invalid scenario world: {}", e));
+            Self::new(generator)
+        }
+        /// Sets the maximum amount of ticks, the run stops earlier if the game is over
+        pub fn max_ticks(mut self, max_ticks: usize) -> Self {
+            self.max_ticks = max_ticks;
+            self
+        }
+        /// Sets the robot's configuration
+        pub fn config(mut self, config: MinerConfig) -> Self {
+            self.config = config;
+            self
+        }
+        /// Sets the backend used to play the robot's sounds
+        pub fn sound(mut self, sound: impl SoundSink + 'static) -> Self {
+            self.sound = Box::new(sound);
+            self
+        }
+        /// Sets the rules used by the robot to walk and pave, the robot's default ones are used otherwise
+        pub fn cost_model(mut self, cost_model: impl TileCostModel + 'static) -> Self {
+            self.cost_model = Some(Box::new(cost_model));
+            self
+        }
+        /// Runs the robot in the scenario's world
+        ///
+        /// # Returns
+        ///
+        /// The ScenarioResult used to check the end state
+        ///
+        /// # Panics
+        ///
+        /// Panics if the Runner cannot be created
+        pub fn run(self) -> ScenarioResult {
+            let mut generator = self.generator;
+            let initial_map = generator.map().clone();
+
+            let mut robot = MinerRobot::with_config(self.config.clone());
+            robot.sound = self.sound;
+            if let Some(cost_model) = self.cost_model {
+                robot.cost_model = cost_model;
+            }
+            // the result is checked with the same rules the robot used
+            let initial_walkable = initial_map.iter()
+                .map(|tiles| tiles.iter().map(|tile| robot.is_walkable(&tile.tile_type)).collect())
+                .collect();
+            let game_over = robot.game_over.clone();
+            let status = robot.status.clone();
+
+            let probe = ScenarioProbe::new(robot);
+            let record = probe.record.clone();
+            let mut runner = Runner::new(Box::new(probe), &mut generator).unwrap_or_else(|e| panic!("Cannot create the runner: {:?}", e));
+
+            let mut ticks = 0;
+            let mut runner_error = None;
+            while ticks < self.max_ticks && !*game_over.lock().unwrap() {
+                if let Err(e) = runner.game_tick() {
+                    runner_error = Some(format!("{:?}", e));
+                    break;
+                }
+                ticks += 1;
+            }
+
+            let record = record.lock().unwrap().clone();
+            let status = status.lock().unwrap().clone();
+            let game_over = *game_over.lock().unwrap();
+            ScenarioResult {
+                ticks,
+                initial_map,
+                initial_walkable,
+                record,
+                state: status.state,
+                bridges_built: status.bridges_built,
+                finish_reason: status.finish_reason,
+                last_bridge_plan: status.last_bridge_plan,
+                game_over,
+                runner_error,
+                min_energy_threshold: self.config.min_energy
+            }
+        }
+    }
+
+    /// What the probe observed while the robot was running
+    ///
+    /// # Notes
+    ///
+    /// The interface functions send their events to the MinerRobot and not to the probe,
+    /// so the moves and the energy are read from the robot's trail and EnergyLedger at the end of every tick
+    #[derive(Debug, Clone, Default)]
+    pub struct ScenarioRecord {
+        /// the last coordinates the robot moved to, in order, see the MinerRobot's trail
+        pub trail: Vec<(usize, usize)>,
+        /// every coordinate the robot stood on
+        pub visited: HashSet<(usize, usize)>,
+        /// the lowest energy level observed after an action
+        pub min_energy: usize,
+        /// the robot's known map at the end of the last tick
+        pub known_map: Vec<Vec<Option<Tile>>>,
+        /// whether the robot could walk on each tile of its known map at the end of the last tick, false for the unknown tiles
+        pub walkable: Vec<Vec<bool>>,
+        /// the rocks in the robot's backpack at the end of the last tick
+        pub rocks: usize
+    }
+
+    /// Runnable that wraps the MinerRobot and records what happens during the run
+    struct ScenarioProbe {
+        robot: MinerRobot,
+        record: Arc<Mutex<ScenarioRecord>>
+    }
+
+    impl ScenarioProbe {
+        fn new(robot: MinerRobot) -> Self {
+            let record = ScenarioRecord {
+                min_energy: robot.get_energy().get_energy_level(),
+                ..ScenarioRecord::default()
+            };
+            Self { robot, record: Arc::new(Mutex::new(record)) }
+        }
+    }
+
+    impl Runnable for ScenarioProbe {
+        fn process_tick(&mut self, world: &mut World) {
+            self.robot.process_tick(world);
+
+            let mut record = self.record.lock().unwrap();
+            record.trail = self.robot.trail.iter().copied().collect();
+            record.visited = self.robot.visited.clone();
+            if let Some(lowest) = self.robot.energy_ledger.lowest_level {
+                record.min_energy = record.min_energy.min(lowest);
+            }
+            if let Some(map) = robot_map(world) {
+                record.walkable = map.iter()
+                    .map(|tiles| tiles.iter().map(|tile| tile.as_ref().map_or(false, |tile| self.robot.is_walkable(&tile.tile_type))).collect())
+                    .collect();
+                record.known_map = map;
+            }
+            record.rocks = self.robot.get_backpack().get_contents().get(&Content::Rock(0)).copied().unwrap_or(0);
+        }
+        fn handle_event(&mut self, event: Event) {
+            // only the Runner's own events get here, the ones of the robot's actions go straight to the robot
+            self.robot.handle_event(event);
+        }
+        fn get_energy(&self) -> &Energy {
+            self.robot.get_energy()
+        }
+        fn get_energy_mut(&mut self) -> &mut Energy {
+            self.robot.get_energy_mut()
+        }
+        fn get_coordinate(&self) -> &Coordinate {
+            self.robot.get_coordinate()
+        }
+        fn get_coordinate_mut(&mut self) -> &mut Coordinate {
+            self.robot.get_coordinate_mut()
+        }
+        fn get_backpack(&self) -> &BackPack {
+            self.robot.get_backpack()
+        }
+        fn get_backpack_mut(&mut self) -> &mut BackPack {
+            self.robot.get_backpack_mut()
+        }
+    }
+
+    /// End state of a scenario, with the assertions used by the tests
+    ///
+    /// # Notes
+    ///
+    /// Every assert_* method panics with a description of the failure and returns self, so that assertions can be chained
+    #[derive(Debug, Clone)]
+    pub struct ScenarioResult {
+        pub ticks: usize,
+        pub initial_map: Vec<Vec<Tile>>,
+        /// whether the robot could walk on each tile of the initial map, following the scenario's TileCostModel
+        pub initial_walkable: Vec<Vec<bool>>,
+        pub record: ScenarioRecord,
+        pub state: Option<RobotState>,
+        pub bridges_built: usize,
+        pub finish_reason: Option<FinishReason>,
+        pub last_bridge_plan: Option<BridgePlan>,
+        pub game_over: bool,
+        pub runner_error: Option<String>,
+        min_energy_threshold: usize
+    }
+
+    impl ScenarioResult {
+        /// Checks whether the robot stood on the island that contains the given tile in the initial map
+        ///
+        /// # Arguments
+        ///
+        /// * `(row, col)` - any tile of the island
+        pub fn reached_island(&self, (row, col): (usize, usize)) -> bool {
+            let rows = self.initial_walkable.len();
+            let cols = self.initial_walkable.first().map(|tiles| tiles.len()).unwrap_or(0);
+            let mut tracker = IslandTracker::new();
+            tracker.update(rows, cols, self.initial_walkable.iter().flatten().copied().collect());
+            match tracker.island_of((row as i32, col as i32)) {
+                Some(island) => self.record.visited.iter().any(|(r, c)| tracker.island_of((*r as i32, *c as i32)) == Some(island)),
+                None => false
+            }
+        }
+        /// Checks whether a tile that wasn't walkable in the initial map has been paved
+        ///
+        /// # Arguments
+        ///
+        /// * `(row, col)` - the tile's coordinates
+        pub fn is_paved(&self, (row, col): (usize, usize)) -> bool {
+            let was_walkable = self.initial_walkable.get(row).and_then(|tiles| tiles.get(col)).copied().unwrap_or(true);
+            let is_walkable = self.record.walkable.get(row).and_then(|tiles| tiles.get(col)).copied().unwrap_or(false);
+            !was_walkable && is_walkable
+        }
+        pub fn assert_reached_island(&self, tile: (usize, usize)) -> &Self {
+            assert!(self.reached_island(tile), "The robot never reached the island containing {:?}, trail: {:?}", tile, self.record.trail);
+            self
+        }
+        pub fn assert_bridge_at(&self, tiles: &[(usize, usize)]) -> &Self {
+            let unpaved: Vec<&(usize, usize)> = tiles.iter().filter(|tile| !self.is_paved(**tile)).collect();
+            assert!(unpaved.is_empty(), "The tiles {:?} have not been paved", unpaved);
+            self
+        }
+        pub fn assert_bridges_built_at_least(&self, bridges: usize) -> &Self {
+            assert!(self.bridges_built >= bridges, "Expected at least {} bridges, {} were built", bridges, self.bridges_built);
+            self
+        }
+        pub fn assert_rocks_at_least(&self, rocks: usize) -> &Self {
+            assert!(self.record.rocks >= rocks, "Expected at least {} rocks in the backpack, found {}", rocks, self.record.rocks);
+            self
+        }
+        pub fn assert_game_over(&self, reason: FinishReason) -> &Self {
+            assert!(self.game_over, "The game is not over after {} ticks, state: {:?}", self.ticks, self.state);
+            assert_eq!(self.finish_reason, Some(reason), "The game ended for a different reason");
+            self
+        }
+        pub fn assert_energy_never_below(&self, energy: usize) -> &Self {
+            assert!(self.record.min_energy >= energy, "The energy dropped to {}, below {}", self.record.min_energy, energy);
+            self
+        }
+        /// Checks that the energy never dropped below the configured min_energy
+        pub fn assert_energy_never_below_min(&self) -> &Self {
+            self.assert_energy_never_below(self.min_energy_threshold)
+        }
+        pub fn assert_no_runner_error(&self) -> &Self {
+            assert!(self.runner_error.is_none(), "The runner failed: {:?}", self.runner_error);
+            self
+        }
+    }
+}
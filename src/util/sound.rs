@@ -0,0 +1,113 @@
+pub mod sound {
+    // std
+    use std::sync::{Arc, Mutex};
+
+    // robotics lib
+    use robotics_lib::world::World;
+
+    // tools
+    #[cfg(feature = "sound")]
+    use OwnerSheeps_Sound_Tool::functions::destroying_sound::play_sound_mining_rock;
+    #[cfg(feature = "sound")]
+    use OwnerSheeps_Sound_Tool::functions::put_sounds::{play_sound_rock_in_g_h_s_s, play_sound_rock_in_lava, play_sound_rock_in_water};
+    #[cfg(feature = "sound")]
+    use OwnerSheeps_Sound_Tool::functions::weather_sounds::weather_sound;
+
+    /// The sounds played by the MinerRobot
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum SoundEvent {
+        Weather,
+        MiningRock,
+        RockInWater,
+        RockInLava,
+        RockInGrassHillSandSnow
+    }
+
+    impl SoundEvent {
+        /// Returns the name of the sound, matching the sound tool's file names
+        pub fn name(&self) -> &'static str {
+            match self {
+                SoundEvent::Weather => "weather",
+                SoundEvent::MiningRock => "mining_rock",
+                SoundEvent::RockInWater => "rock_in_water",
+                SoundEvent::RockInLava => "rock_in_lava",
+                SoundEvent::RockInGrassHillSandSnow => "rock_in_g_h_s_s"
+            }
+        }
+    }
+
+    /// Something that can play the robot's sounds
+    pub trait SoundSink: Send {
+        /// Plays the given sound
+        ///
+        /// # Arguments
+        ///
+        /// * `event` - the sound to play
+        /// * `world` - the world, used by the sounds that depend on it (e.g. the weather)
+        fn play(&mut self, event: SoundEvent, world: &World);
+    }
+
+    /// Plays the sounds on the audio device through the sound tool
+    ///
+    /// # Notes
+    ///
+    /// Without the `sound` feature the sound tool is not compiled and every sound is ignored
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct DeviceSound;
+
+    #[cfg(feature = "sound")]
+    impl SoundSink for DeviceSound {
+        fn play(&mut self, event: SoundEvent, world: &World) {
+            match event {
+                SoundEvent::Weather => weather_sound(world),
+                SoundEvent::MiningRock => play_sound_mining_rock(),
+                SoundEvent::RockInWater => play_sound_rock_in_water(),
+                SoundEvent::RockInLava => play_sound_rock_in_lava(),
+                SoundEvent::RockInGrassHillSandSnow => play_sound_rock_in_g_h_s_s()
+            }
+        }
+    }
+
+    #[cfg(not(feature = "sound"))]
+    impl SoundSink for DeviceSound {
+        fn play(&mut self, _event: SoundEvent, _world: &World) {}
+    }
+
+    /// Ignores every sound
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct SilentSound;
+
+    impl SoundSink for SilentSound {
+        fn play(&mut self, _event: SoundEvent, _world: &World) {}
+    }
+
+    /// Stores the sequence of played sounds without playing them
+    ///
+    /// # Notes
+    ///
+    /// Clones share the same sequence, so a clone can be kept to read the sounds after the robot has been given to the Runner
+    #[derive(Debug, Clone, Default)]
+    pub struct RecordingSound {
+        events: Arc<Mutex<Vec<SoundEvent>>>
+    }
+
+    impl RecordingSound {
+        pub fn new() -> Self {
+            Self::default()
+        }
+        /// Returns the sounds played so far, in order
+        pub fn events(&self) -> Vec<SoundEvent> {
+            self.events.lock().unwrap().clone()
+        }
+        /// Returns true if the given sound has been played at least once
+        pub fn played(&self, event: SoundEvent) -> bool {
+            self.events.lock().unwrap().contains(&event)
+        }
+    }
+
+    impl SoundSink for RecordingSound {
+        fn play(&mut self, event: SoundEvent, _world: &World) {
+            self.events.lock().unwrap().push(event);
+        }
+    }
+}
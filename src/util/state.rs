@@ -0,0 +1,225 @@
+pub mod state {
+    // MinerRobot
+    use crate::{BridgePlan, FinishReason, IslandInfo, MinerError, MinerRobot, RobotState};
+    use crate::util::bridge::debug::BridgeStatus;
+
+    // robotics lib
+    use robotics_lib::world::tile::Content;
+    use robotics_lib::world::World;
+
+    /// A change of state of the robot
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct StateTransition {
+        pub tick: usize,
+        pub from: RobotState,
+        pub to: RobotState
+    }
+
+    /// Snapshot of the robot's progress, shared with whoever runs the robot
+    #[derive(Debug, Clone, Default)]
+    pub struct RobotStatus {
+        pub tick: usize,
+        pub state: Option<RobotState>,
+        pub rocks_collected: usize,
+        pub bridges_built: usize,
+        pub finish_reason: Option<FinishReason>,
+        /// the last bridge the robot decided to build
+        pub last_bridge_plan: Option<BridgePlan>,
+        /// estimated ticks before the robot starts paving the next bridge
+        pub ticks_until_bridge: Option<usize>,
+        /// the islands found during the last scan
+        pub islands: Vec<IslandInfo>
+    }
+
+    impl RobotState {
+        /// Checks whether the robot can go from self to the next state
+        ///
+        /// # Arguments
+        ///
+        /// * `next` - the next state
+        ///
+        /// # Returns
+        ///
+        /// A bool stating whether the transition is valid or not
+        ///
+        /// # Notes
+        ///
+        /// Finished is a final state, while Recharging, Stuck and Finished can be reached from every other state
+        pub fn can_transition_to(&self, next: &RobotState) -> bool {
+            use RobotState::*;
+            match (self, next) {
+                (Finished(_), _) => false,
+                (_, Finished(_)) | (_, Stuck) => true,
+                (Recharging, Recharging) => false,
+                (Recharging, _) | (_, Recharging) => true,
+                (Exploring, TravellingToTarget) | (Exploring, MovingToBridgeStart) => true,
+                (TravellingToTarget, CollectingRocks) | (TravellingToTarget, Exploring) => true,
+                (CollectingRocks, TravellingToTarget) | (CollectingRocks, MovingToBridgeStart) | (CollectingRocks, Exploring) => true,
+                (MovingToBridgeStart, PavingBridge) | (MovingToBridgeStart, TravellingToTarget) | (MovingToBridgeStart, Exploring) => true,
+                (PavingBridge, Exploring) | (PavingBridge, TravellingToTarget) => true,
+                (Stuck, Exploring) => true,
+                _ => false
+            }
+        }
+        /// Returns true if the robot has finished its run
+        pub fn is_finished(&self) -> bool {
+            matches!(self, RobotState::Finished(_))
+        }
+    }
+
+    impl MinerRobot {
+        /// Moves the robot to the next state and records the transition
+        ///
+        /// # Arguments
+        ///
+        /// * `next` - the next state
+        ///
+        /// # Returns
+        ///
+        /// MinerError::InvalidTransition if the robot can't go from the current state to the next one
+        pub fn transition_to(&mut self, next: RobotState) -> Result<(), MinerError> {
+            if self.state == next {
+                return Ok(());
+            }
+            if !self.state.can_transition_to(&next) {
+                return Err(MinerError::InvalidTransition { from: self.state.clone(), to: next });
+            }
+
+            let transition = StateTransition { tick: self.tick, from: self.state.clone(), to: next.clone() };
+            self.state_history.push_back(transition);
+            while self.state_history.len() > self.config.state_history_size {
+                self.state_history.pop_front();
+            }
+            self.state = next;
+            Ok(())
+        }
+        /// Updates the shared status with the robot's current progress
+        pub(crate) fn publish_status(&self) {
+            let mut status = self.status.lock().unwrap();
+            status.tick = self.tick;
+            status.state = Some(self.state.clone());
+            status.rocks_collected = self.rocks_collected;
+            status.bridges_built = self.bridges_built;
+            if let Some(plan) = &self.bridge_target {
+                status.last_bridge_plan = Some(plan.clone());
+            }
+            status.ticks_until_bridge = self.rock_budget.as_ref().and_then(|budget| budget.ticks_until_bridge);
+            status.islands = self.known_islands.clone();
+            status.finish_reason = match &self.state {
+                RobotState::Finished(reason) => Some(reason.clone()),
+                _ => None
+            };
+        }
+        /// Returns the most recent state transitions, from the oldest to the newest
+        pub fn state_history(&self) -> impl Iterator<Item = &StateTransition> {
+            self.state_history.iter()
+        }
+        /// Ends the game with the given reason
+        ///
+        /// # Arguments
+        ///
+        /// * `reason` - the reason why the robot stopped
+        pub fn finish(&mut self, reason: FinishReason) {
+            if !self.state.is_finished() {
+                // Finished can be reached from every state that isn't final
+                let _ = self.transition_to(RobotState::Finished(reason));
+            }
+            self.publish_status();
+            self.game_is_over();
+            println!("{:?}", self);
+        }
+        /// Runs the behaviour associated to the current state
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        pub(crate) fn run_state(&mut self, world: &mut World) -> Result<(), MinerError> {
+            match self.state.clone() {
+                RobotState::Exploring => self.explore(world),
+                RobotState::TravellingToTarget => self.travel_to_target(world),
+                RobotState::CollectingRocks => self.collect_target(world),
+                RobotState::MovingToBridgeStart => self.move_to_bridge_start(world),
+                RobotState::PavingBridge => self.pave(world),
+                RobotState::Recharging => self.recharge(world),
+                RobotState::Stuck => self.unstick(world),
+                RobotState::Finished(_) => Ok(())
+            }
+        }
+        /// Scans the area around the robot and decides whether to gather rocks or to build a bridge
+        fn explore(&mut self, world: &mut World) -> Result<(), MinerError> {
+            self.scan_world(world, self.scan_distance)?;
+            let map = self.get_known_map(world);
+            self.known_islands = self.get_island_infos(&map);
+
+            // managing the creation/deletion of goals
+            self.handle_goals();
+
+            if self.can_afford_bridge(world) {
+                self.transition_to(RobotState::MovingToBridgeStart)
+            } else {
+                self.transition_to(RobotState::TravellingToTarget)
+            }
+        }
+        /// Moves the robot next to the cheapest deposit of the rock budget, or to the closest rock if there is no bridge to plan
+        fn travel_to_target(&mut self, world: &mut World) -> Result<(), MinerError> {
+            match self.plan_rock_budget(world) {
+                Ok(budget) if !budget.deposits.is_empty() => {
+                    println!("{}", budget);
+                    let deposit = &budget.deposits[0];
+                    self.move_next_to_tile(world, vec![(deposit.path_cost, deposit.coordinates)])?;
+                }
+                _ => self.move_next_to_content(world, Content::Rock(1))?
+            }
+            self.transition_to(RobotState::CollectingRocks)
+        }
+        /// Collects the rock that the robot is facing
+        fn collect_target(&mut self, world: &mut World) -> Result<(), MinerError> {
+            self.destroy_facing_content(world)?;
+
+            if self.can_afford_bridge(world) {
+                self.transition_to(RobotState::MovingToBridgeStart)
+            } else {
+                self.transition_to(RobotState::TravellingToTarget)
+            }
+        }
+        /// Moves the robot to the starting tile of the bridge
+        fn move_to_bridge_start(&mut self, world: &mut World) -> Result<(), MinerError> {
+            match self.prepare_bridge(world)? {
+                BridgeStatus::NotEnoughRocks => self.transition_to(RobotState::TravellingToTarget),
+                BridgeStatus::MovingToStart => Ok(()),
+                BridgeStatus::Ready(plan) => {
+                    println!("{}", plan);
+                    self.bridge_target = Some(plan);
+                    self.transition_to(RobotState::PavingBridge)
+                }
+            }
+        }
+        /// Builds the bridge towards the target chosen while moving to the starting tile
+        fn pave(&mut self, world: &mut World) -> Result<(), MinerError> {
+            if let Some(plan) = self.bridge_target.take() {
+                self.execute_bridge(world, &plan)?;
+                // the rocks ran out before the end of the bridge, so the robot goes to collect more
+                if self.bridge_progress.is_some() {
+                    return self.transition_to(RobotState::TravellingToTarget);
+                }
+                // the robot is on a new island, so it needs to be scanned
+                self.world_scanned = false;
+            }
+            self.transition_to(RobotState::Exploring)
+        }
+        /// Recharges the robot and goes back to the state it was in
+        fn recharge(&mut self, world: &mut World) -> Result<(), MinerError> {
+            self.recharge_energy(world);
+            let next = self.resume_state.take().unwrap_or(RobotState::Exploring);
+            self.transition_to(next)
+        }
+        /// Widens the scan so that the robot can find a new way
+        fn unstick(&mut self, world: &mut World) -> Result<(), MinerError> {
+            self.widen_scan(world);
+            if self.state.is_finished() {
+                return Ok(());
+            }
+            self.transition_to(RobotState::Exploring)
+        }
+    }
+}
@@ -0,0 +1,183 @@
+pub mod target {
+    // std
+    use std::cmp::{Ordering, Reverse};
+
+    // MinerRobot
+    use crate::{IslandInfo, KnownMap, MinerRobot};
+
+    // serde
+    use serde::{Deserialize, Serialize};
+
+    /// An island that can be reached with a bridge from the robot's island
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TargetCandidate {
+        /// index of the island in get_islands()
+        pub island: usize,
+        /// stable id of the island
+        pub island_id: usize,
+        /// cheapest bridge from the robot's island, from start to end
+        pub path: Vec<(i32, i32)>,
+        /// rocks needed to pave the bridge
+        pub bridge_rocks: usize,
+        /// Manhattan distance between the robot and the closest tile of the island
+        pub distance: usize,
+        /// amount of tiles of the island
+        pub area: usize,
+        /// rocks lying on the island
+        pub rocks: usize,
+        /// amount of tiles of the island that have some content
+        pub content: usize,
+        /// amount of tiles of the island next to a tile the robot hasn't discovered yet
+        pub frontier: usize
+    }
+
+    impl TargetCandidate {
+        /// Returns how much reaching the island is worth
+        pub fn benefit(&self) -> usize {
+            self.area + self.content + self.frontier
+        }
+        /// Returns the benefit of the island for each rock spent on the bridge
+        pub fn value(&self) -> f64 {
+            self.benefit() as f64 / self.bridge_rocks.max(1) as f64
+        }
+    }
+
+    /// Decides which island the robot builds the next bridge to
+    ///
+    /// # Notes
+    ///
+    /// When more candidates are equally good the first one, i.e. the one with the lowest index, has to be chosen
+    pub trait TargetSelector: Send {
+        /// Chooses the target among the islands that can be reached
+        ///
+        /// # Arguments
+        ///
+        /// * `candidates` - the islands that can be reached, sorted by index
+        ///
+        /// # Returns
+        ///
+        /// The chosen candidate, or None if no island is worth a bridge
+        fn select<'a>(&self, candidates: &'a [TargetCandidate]) -> Option<&'a TargetCandidate>;
+    }
+
+    /// Picks the island closest to the robot, the MinerRobot's original behaviour
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct ClosestTarget;
+
+    impl TargetSelector for ClosestTarget {
+        fn select<'a>(&self, candidates: &'a [TargetCandidate]) -> Option<&'a TargetCandidate> {
+            candidates.iter().min_by_key(|candidate| candidate.distance)
+        }
+    }
+
+    /// Picks the island with the most tiles
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct LargestTarget;
+
+    impl TargetSelector for LargestTarget {
+        fn select<'a>(&self, candidates: &'a [TargetCandidate]) -> Option<&'a TargetCandidate> {
+            candidates.iter().min_by_key(|candidate| Reverse(candidate.area))
+        }
+    }
+
+    /// Picks the island with the most rocks, then the one with the most content
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct RichestTarget;
+
+    impl TargetSelector for RichestTarget {
+        fn select<'a>(&self, candidates: &'a [TargetCandidate]) -> Option<&'a TargetCandidate> {
+            candidates.iter().min_by_key(|candidate| Reverse((candidate.rocks, candidate.content)))
+        }
+    }
+
+    /// Picks the island with the longest border towards the undiscovered world
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct FrontierTarget;
+
+    impl TargetSelector for FrontierTarget {
+        fn select<'a>(&self, candidates: &'a [TargetCandidate]) -> Option<&'a TargetCandidate> {
+            candidates.iter().min_by_key(|candidate| Reverse(candidate.frontier))
+        }
+    }
+
+    /// Picks the island with the highest benefit for each rock spent on the bridge
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct BestValueTarget;
+
+    impl TargetSelector for BestValueTarget {
+        fn select<'a>(&self, candidates: &'a [TargetCandidate]) -> Option<&'a TargetCandidate> {
+            candidates.iter().min_by(|a, b| b.value().partial_cmp(&a.value()).unwrap_or(Ordering::Equal))
+        }
+    }
+
+    /// The built-in TargetSelectors, used to choose one from the configuration
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum TargetStrategy {
+        Closest,
+        LargestArea,
+        MostContent,
+        MostFrontier,
+        BestValue
+    }
+
+    impl TargetStrategy {
+        /// Returns the TargetSelector that implements the strategy
+        pub fn selector(&self) -> Box<dyn TargetSelector> {
+            match self {
+                TargetStrategy::Closest => Box::new(ClosestTarget),
+                TargetStrategy::LargestArea => Box::new(LargestTarget),
+                TargetStrategy::MostContent => Box::new(RichestTarget),
+                TargetStrategy::MostFrontier => Box::new(FrontierTarget),
+                TargetStrategy::BestValue => Box::new(BestValueTarget)
+            }
+        }
+    }
+
+    impl MinerRobot {
+        /// Describes every island that can be reached with a bridge from the robot's island
+        ///
+        /// # Arguments
+        ///
+        /// * `map` - the known world
+        /// * `islands` - the islands returned by get_island_infos()
+        /// * `source` - the index of the robot's island
+        ///
+        /// # Returns
+        ///
+        /// The candidates sorted by island index
+        pub fn get_target_candidates(&self, map: &KnownMap, islands: &[IslandInfo], source: usize) -> Vec<TargetCandidate> {
+            let mut owners = vec![vec![None; map.cols()]; map.rows()];
+            for island in islands {
+                for &(row, col) in &island.tiles {
+                    owners[row as usize][col as usize] = Some(island.index);
+                }
+            }
+            let (robot_row, robot_col) = self.get_coordinates();
+
+            let mut candidates: Vec<TargetCandidate> = self.get_cheapest_bridges(map, &islands[source].tiles, &owners)
+                .into_iter()
+                .map(|(island, path, bridge_rocks)| {
+                    let info = &islands[island];
+                    let distance = info.tiles.iter()
+                        .map(|(row, col)| (row - robot_row as i32).unsigned_abs() as usize + (col - robot_col as i32).unsigned_abs() as usize)
+                        .min()
+                        .unwrap_or(usize::MAX);
+                    TargetCandidate {
+                        island,
+                        island_id: info.id,
+                        path,
+                        bridge_rocks,
+                        distance,
+                        area: info.area,
+                        rocks: info.rocks,
+                        content: info.content.values().sum(),
+                        frontier: info.frontier
+                    }
+                })
+                .collect();
+            candidates.sort_by_key(|candidate| candidate.island);
+            candidates
+        }
+    }
+}
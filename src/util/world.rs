@@ -1,6 +1,9 @@
 pub mod world {
+    // std
+    use std::sync::Arc;
+
     // MinerRobot
-    use crate::{MinerRobot, World};
+    use crate::{KnownCell, KnownMap, MinerRobot, World};
 
     // robotics lib
     use robotics_lib::interface::robot_map;
@@ -15,6 +18,10 @@ pub mod world {
         /// # Returns
         ///
         /// The known world as a Vec<Vec<Tile>>
+        ///
+        /// # Notes
+        ///
+        /// The tiles that haven't been discovered are DeepWater, use get_known_map() to tell them apart
         pub fn get_map(&self, world: &World) -> Vec<Vec<Tile>> {
             let robot_map = robot_map(world).expect("Error while retrieving the map");
             let world_dim = robot_map.len();
@@ -50,33 +57,104 @@ pub mod world {
         ///
         /// # Returns
         ///
-        /// The known world as a Vec<Vec<Option<Tile>>>
+        /// The known world as a Vec<Vec<Option<Tile>>>, None for the tiles that haven't been discovered
         pub fn get_map_option(&self, world: &World) -> Vec<Vec<Option<Tile>>> {
-            let robot_map = robot_map(world).expect("Error while retrieving the map");
-            let world_dim = robot_map.len();
-            let default_tile = Tile {
-                tile_type: TileType::DeepWater,
-                content: Content::None,
-                elevation: 0
-            };
-            let mut map: Vec<Vec<Option<Tile>>> = vec![vec![Some(default_tile); world_dim];world_dim];
-            for (i, row) in robot_map.iter().enumerate() {
-                for (j, tile) in row.iter().enumerate() {
-                    match tile {
-                        Some(t) => {
-                            map[i][j] = Some(t.clone());
-                        }
-                        None => {
-                            map[i][j] = Some(Tile {
-                                tile_type: TileType::DeepWater,
-                                content: Content::None,
-                                elevation: 0
-                            });
-                        }
-                    };
+            robot_map(world).expect("Error while retrieving the map")
+        }
+        /// Returns the robot's known map, read with the configured UnknownPolicy
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        ///
+        /// # Returns
+        ///
+        /// The known world as a KnownMap, the map used by the island, bridge and movement code
+        ///
+        /// # Notes
+        ///
+        /// The map comes from the cache and is shared with it instead of being copied,
+        /// the world is read only if the cache is stale
+        pub fn get_known_map(&self, world: &World) -> Arc<KnownMap> {
+            if self.known_map_stale {
+                Arc::new(KnownMap::from_robot_map(self.get_map_option(world)).with_policy(self.config.unknown_policy))
+            } else {
+                Arc::clone(&self.known_map)
+            }
+        }
+        /// Returns the cached known map, None if some tiles may have been discovered since it was read
+        pub fn cached_known_map(&self) -> Option<&KnownMap> {
+            if self.known_map_stale {
+                None
+            } else {
+                Some(self.known_map.as_ref())
+            }
+        }
+        /// Brings the cached known map up to date, reading the whole world only if the cache is stale
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        pub(crate) fn sync_known_map(&mut self, world: &World) -> &Arc<KnownMap> {
+            if self.known_map_stale {
+                self.cache_known_map(world);
+            }
+            &self.known_map
+        }
+        /// Replaces the cached known map with the one currently known by the robot
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        pub fn cache_known_map(&mut self, world: &World) {
+            if let Some(map) = robot_map(world) {
+                self.known_map = Arc::new(KnownMap::from_robot_map(map).with_policy(self.config.unknown_policy));
+                self.known_map_stale = false;
+                self.known_map_scans += 1;
+            }
+        }
+        /// Marks the cached known map as stale, used after the tools that discover tiles without an event
+        pub(crate) fn invalidate_known_map(&mut self) {
+            self.known_map_stale = true;
+        }
+        /// Stores the tiles around the robot returned by go() in the cached known map
+        ///
+        /// # Arguments
+        ///
+        /// * `view` - the 3x3 tiles around the robot, None for the tiles out of the map
+        /// * `(row, col)` - the robot's coordinates
+        pub(crate) fn cache_known_view(&mut self, view: &[Vec<Option<Tile>>], (row, col): (usize, usize)) {
+            for (view_row, tiles) in view.iter().enumerate() {
+                for (view_col, tile) in tiles.iter().enumerate() {
+                    let (tile_row, tile_col) = ((row + view_row).checked_sub(1), (col + view_col).checked_sub(1));
+                    if let (Some(tile_row), Some(tile_col), Some(tile)) = (tile_row, tile_col, tile) {
+                        self.cache_known_tile((tile_row, tile_col), tile.clone());
+                    }
                 }
             }
-            map
+        }
+        /// Stores a single tile in the cached known map
+        ///
+        /// # Arguments
+        ///
+        /// * `coordinates` - the tile's coordinates
+        /// * `tile` - the tile
+        ///
+        /// # Notes
+        ///
+        /// If the island tracker follows the cached map, only the changed tile is passed to it.
+        /// The cached map is copied only if a map returned by get_known_map() is still in use
+        pub(crate) fn cache_known_tile(&mut self, coordinates: (usize, usize), tile: Tile) {
+            let tracked = self.island_tracker.get_mut().map_revision() == Some(self.known_map.revision());
+            Arc::make_mut(&mut self.known_map).set(coordinates, KnownCell::Known(tile));
+            if tracked {
+                let (row, col) = (coordinates.0 as i32, coordinates.1 as i32);
+                let walkable = self.is_walkable_at(&self.known_map, row, col);
+                let revision = self.known_map.revision();
+                let tracker = self.island_tracker.get_mut();
+                tracker.set_walkable((row, col), walkable);
+                tracker.set_map_revision(Some(revision));
+            }
         }
         /// Returns all the coordinates of tiles that contain the given content
         ///
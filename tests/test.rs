@@ -1,10 +1,38 @@
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "sim")]
     use std::path::PathBuf;
     use robotics_lib::world::tile::{Content, Tile, TileType};
+    #[cfg(feature = "sim")]
     use worldgen_unwrap::public::WorldgeneratorUnwrap;
     use robotics_lib::world::world_generator::Generator;
+    use robotics_lib::utils::LibError;
+    use robotics_lib::runner::{Runnable, Runner};
+    use robotics_lib::event::events::Event;
+    use robotics_lib::interface::Direction;
+    use holy_crab_best_path::{AsciiMapError, AsciiWorld, BridgePlan, BridgeProgress, ConfigError, FinishReason, Instrumented, IslandGraph, IslandTracker, KnownCell, KnownMap, MemoryWorldGenerator, MinerConfig, MinerError, MinerRobot, Recovery, RecordingSound, RockBudget, RockDeposit, RobotState, Scenario, SilentSound, SoundEvent, StrictCostModel, TargetSelector, TargetStrategy, TileCostModel, UnknownPolicy, WorldError};
+
+    /// Two islands separated by three tiles of shallow water, with rocks on the left one
+    fn two_islands() -> MemoryWorldGenerator {
+        let mut tile_types = vec![vec![TileType::DeepWater; 9]; 9];
+        for row in 2..7 {
+            for col in 0..3 {
+                tile_types[row][col] = TileType::Grass;
+            }
+            for col in 3..6 {
+                tile_types[row][col] = TileType::ShallowWater;
+            }
+            for col in 6..9 {
+                tile_types[row][col] = TileType::Sand;
+            }
+        }
+        MemoryWorldGenerator::from_tile_types(tile_types, (4, 1))
+            .and_then(|generator| generator.with_content((2, 0), Content::Rock(3)))
+            .and_then(|generator| generator.with_content((6, 2), Content::Rock(3)))
+            .unwrap()
+    }
     #[test]
+    #[cfg(feature = "sim")]
     fn test_world_creation() {
         let gui_start = false;
         let path = PathBuf::new().join("world.bin");
@@ -14,6 +42,619 @@ mod tests {
 
 
     }
+    #[test]
+    fn test_memory_world_generator() {
+        let mut generator = two_islands();
+        let (map, spawn, _conditions, _max_score, _score_table) = generator.gen();
+        assert_eq!(spawn, (4, 1));
+        assert_eq!(map[4][4].tile_type, TileType::ShallowWater);
+        assert_eq!(map[2][0].content, Content::Rock(3));
+
+        let robot = MinerRobot::new();
+        let runner = Runner::new(Box::new(robot), &mut generator).unwrap();
+        let coordinate = runner.get_robot().get_coordinate();
+        assert_eq!((coordinate.get_row(), coordinate.get_col()), (4, 1));
+    }
+    #[test]
+    fn test_memory_world_generator_validation() {
+        let not_square = vec![vec![Tile { tile_type: TileType::Grass, content: Content::None, elevation: 0 }; 3]; 2];
+        assert!(matches!(MemoryWorldGenerator::new(not_square, (0, 0)), Err(WorldError::NotSquare { .. })));
+        assert_eq!(MemoryWorldGenerator::from_tile_types(vec![vec![TileType::Grass; 2]; 2], (5, 0)).unwrap_err(), WorldError::SpawnOutOfBounds((5, 0)));
+        assert_eq!(MemoryWorldGenerator::new(vec![], (0, 0)).unwrap_err(), WorldError::EmptyMap);
+        let generator = MemoryWorldGenerator::from_tile_types(vec![vec![TileType::Grass; 2]; 2], (0, 0)).unwrap();
+        assert_eq!(generator.clone().with_content((1, 2), Content::Rock(1)).unwrap_err(), WorldError::ContentOutOfBounds((1, 2)));
+        assert_eq!(generator.with_content((1, 1), Content::Rock(1)).unwrap().map()[1][1].content, Content::Rock(0));
+    }
+    #[test]
+    fn test_ascii_scenario_round_trip() {
+        let text = include_str!("scenarios/two_islands.txt");
+        let world = AsciiWorld::parse(text).unwrap();
+        assert_eq!(world.spawn, (4, 1));
+        assert_eq!(world.map[3][4].tile_type, TileType::ShallowWater);
+        assert_eq!(world.map.iter().flatten().filter(|tile| tile.content == Content::Rock(0)).count(), 6);
+
+        // writing the world and reading it again gives the same map
+        let written = world.to_ascii();
+        let read = AsciiWorld::parse(&written).unwrap();
+        assert_eq!(read.spawn, world.spawn);
+        assert_eq!(read.to_ascii(), written);
+
+        let mut generator = read.into_generator().unwrap();
+        assert_eq!(generator.gen().0.len(), 9);
+    }
+    #[test]
+    fn test_ascii_scenario_errors() {
+        assert_eq!(AsciiWorld::parse("GG\nGG\n").unwrap_err(), AsciiMapError::MissingSpawn);
+        assert_eq!(AsciiWorld::parse("@@\nGG\n").unwrap_err(), AsciiMapError::MultipleSpawns);
+        assert_eq!(AsciiWorld::parse("@G\nGX\n").unwrap_err(), AsciiMapError::UnknownTile { line: 2, col: 2, found: 'X' });
+        assert_eq!(AsciiWorld::parse("@G\nG\n").unwrap_err(), AsciiMapError::RaggedRow { line: 2, len: 1, expected: 2 });
+        assert_eq!(AsciiWorld::parse("@G\nGG\n\n++\n").unwrap_err(), AsciiMapError::LayerSizeMismatch { tiles: 2, contents: 1 });
+    }
+    #[test]
+    fn test_scenario_collects_rocks() {
+        let result = Scenario::from_ascii(include_str!("scenarios/two_islands.txt")).max_ticks(30).run();
+        result.assert_no_runner_error()
+            .assert_rocks_at_least(1)
+            .assert_reached_island((4, 1));
+    }
+    #[test]
+    fn test_scenario_result_needs_the_robot_to_leave_its_island() {
+        // a single tick is not enough to build a bridge, so the robot is still on its island
+        let result = Scenario::from_ascii(include_str!("scenarios/two_islands.txt")).max_ticks(1).run();
+        assert!(result.reached_island((4, 1)));
+        assert!(!result.reached_island((4, 7)));
+        assert!(std::panic::catch_unwind(|| { result.assert_reached_island((4, 7)); }).is_err());
+    }
+    #[test]
+    fn test_scenario_builds_bridge_to_the_right_island() {
+        let result = Scenario::from_ascii(include_str!("scenarios/two_islands.txt")).max_ticks(200).run();
+        result.assert_no_runner_error()
+            .assert_bridges_built_at_least(1)
+            .assert_reached_island((4, 7));
+        assert!((2..7).any(|row| result.is_paved((row, 3)) && result.is_paved((row, 4)) && result.is_paved((row, 5))));
+    }
+    #[test]
+    fn test_scenario_result_follows_the_cost_model() {
+        let result = Scenario::from_ascii("@WG\nDWD\nDWD\n").max_ticks(0).run();
+        assert!(result.initial_walkable[0][1]);
+
+        // with the strict rules the walls are not walkable, so they can't count as paved or as part of an island
+        let result = Scenario::from_ascii("@WG\nDWD\nDWD\n").cost_model(StrictCostModel).max_ticks(0).run();
+        assert!(!result.initial_walkable[0][1]);
+        assert!(!result.is_paved((0, 1)));
+        assert!(!result.reached_island((0, 1)));
+    }
+    #[test]
+    fn test_scenario_bridge_takes_the_cheapest_route() {
+        let sound = RecordingSound::new();
+        let result = Scenario::from_ascii(include_str!("scenarios/lava_or_shallow.txt")).max_ticks(300).sound(sound.clone()).run();
+        result.assert_no_runner_error()
+            .assert_bridges_built_at_least(1)
+            .assert_bridge_at(&[(1, 3), (1, 4), (1, 5), (1, 6)]);
+        assert!(!result.is_paved((3, 4)));
+        assert!(!sound.played(SoundEvent::RockInLava));
+
+        // the plan can be inspected and serialized
+        let plan = result.last_bridge_plan.clone().unwrap();
+        assert_eq!(plan.total_rocks, 8);
+        assert_eq!(plan.tiles.iter().map(|tile| tile.coordinates).collect::<Vec<_>>(), vec![(1, 3), (1, 4), (1, 5), (1, 6)]);
+        assert!(plan.to_string().contains("8 rocks"));
+        let json = serde_json::to_string(&plan).unwrap();
+        assert_eq!(serde_json::from_str::<BridgePlan>(&json).unwrap(), plan);
+    }
+    #[test]
+    fn test_cheapest_bridge_prefers_fewer_rocks() {
+        let world = AsciiWorld::parse(include_str!("scenarios/lava_or_shallow.txt")).unwrap();
+        let robot = MinerRobot::new();
+        let islands = robot.get_islands(&world.known_map());
+        let left = islands.iter().find(|island| island.contains(&(3, 1))).unwrap();
+        let right = islands.iter().find(|island| island.contains(&(3, 7))).unwrap();
+
+        let (path, cost) = robot.get_cheapest_bridge(&world.known_map(), left, right).unwrap();
+        assert_eq!(cost, 8);
+        assert_eq!(path.first(), Some(&(1, 2)));
+        assert_eq!(path.last(), Some(&(1, 7)));
+        // the L-shaped bridge would have crossed the lava
+        assert_eq!(robot.get_paving_cost(&world.known_map(), (3, 2), (3, 6)), 9);
+    }
+    #[test]
+    fn test_bridge_network_connects_every_island() {
+        let world = AsciiWorld::parse(include_str!("scenarios/archipelago.txt")).unwrap();
+        let robot = MinerRobot::new();
+        let network = robot.plan_bridge_network(&world.known_map());
+        assert_eq!(network.islands, 3);
+        assert_eq!(network.bridges.len(), 2);
+        assert_eq!(network.total_rocks, 6);
+
+        // starting from the left island the cheapest bridge comes first
+        let schedule = network.schedule(0);
+        assert_eq!(schedule.iter().map(|plan| (plan.source_island, plan.target_island, plan.total_rocks)).collect::<Vec<_>>(), vec![(0, 1, 2), (1, 2, 4)]);
+
+        // starting from the right island the bridges are built from the other side
+        let schedule = network.schedule(2);
+        assert_eq!(schedule.iter().map(|plan| (plan.source_island, plan.target_island)).collect::<Vec<_>>(), vec![(2, 1), (1, 0)]);
+        assert_eq!(schedule[0].start, (2, 8));
+        assert!(network.next_affordable(2, 3).is_none());
+    }
+    #[test]
+    fn test_island_graph_export() {
+        let world = AsciiWorld::parse(include_str!("scenarios/archipelago.txt")).unwrap();
+        let graph = MinerRobot::new().get_island_graph(&world.known_map());
+        assert_eq!(graph.nodes.iter().map(|node| node.size).collect::<Vec<_>>(), vec![4, 4, 2]);
+        // the L-shaped estimate pays for the tiles of the middle island too
+        assert_eq!(graph.edges.iter().map(|edge| (edge.source, edge.target, edge.rocks)).collect::<Vec<_>>(), vec![(0, 1, 2), (0, 2, 8), (1, 2, 4)]);
+        assert_eq!((graph.edges[0].start, graph.edges[0].end), ((2, 2), (2, 4)));
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("graph islands {"));
+        assert!(dot.contains("2 [label=\"island 2 (#2)\\n2 tiles\"];"));
+        assert!(dot.contains("1 -- 2 [label=\"4 rocks\"];"));
+
+        let dot_path = std::env::temp_dir().join("holy_crab_islands.dot");
+        let json_path = std::env::temp_dir().join("holy_crab_islands.json");
+        graph.write(&dot_path, &json_path).unwrap();
+        assert_eq!(std::fs::read_to_string(&dot_path).unwrap(), dot);
+        assert_eq!(serde_json::from_str::<IslandGraph>(&std::fs::read_to_string(&json_path).unwrap()).unwrap(), graph);
+    }
+    #[test]
+    fn test_island_tracker_follows_the_paved_tiles() {
+        let world = AsciiWorld::parse(include_str!("scenarios/archipelago.txt")).unwrap();
+        let robot = MinerRobot::new();
+        assert_eq!(robot.get_islands(&world.known_map()).len(), 3);
+
+        // paving the water between the first two islands joins them
+        let mut map = world.map.clone();
+        map[2][3].tile_type = TileType::Street;
+        let islands = robot.get_islands(&KnownMap::from(map));
+        assert_eq!(islands.len(), 2);
+        assert!(islands[0].contains(&(2, 1)) && islands[0].contains(&(2, 4)));
+
+        // going back to the original map splits them again
+        assert_eq!(robot.get_islands(&world.known_map()).len(), 3);
+
+        // a continent too large for a recursive search
+        let continent = vec![vec![Tile { tile_type: TileType::Grass, content: Content::None, elevation: 0 }; 600]; 600];
+        let islands = robot.get_islands(&KnownMap::from(continent));
+        assert_eq!(islands.len(), 1);
+        assert_eq!(islands[0].len(), 360_000);
+    }
+    #[test]
+    fn test_known_map_cache_follows_the_events() {
+        let world = AsciiWorld::parse(include_str!("scenarios/archipelago.txt")).unwrap();
+        let mut robot = MinerRobot::new();
+        assert!(robot.cached_known_map().is_none());
+        robot.known_map = world.known_map().into();
+        robot.known_map_stale = false;
+
+        // the tile reached by the robot is stored without reading the world again
+        let street = Tile { tile_type: TileType::Street, content: Content::None, elevation: 0 };
+        robot.handle_event(Event::Moved(street, (2, 3)));
+        assert_eq!(robot.known_map_scans, 0);
+        let map = robot.cached_known_map().unwrap().clone();
+        assert_eq!(map.tile(2, 3).map(|tile| tile.tile_type.clone()), Some(TileType::Street));
+        assert_eq!(robot.get_islands(&map).len(), 2);
+    }
+    #[test]
+    fn test_island_tracker_follows_the_tile_events() {
+        let world = AsciiWorld::parse(include_str!("scenarios/archipelago.txt")).unwrap();
+        let mut robot = MinerRobot::new();
+        robot.known_map = world.known_map().into();
+        robot.known_map_stale = false;
+        let islands = robot.get_islands(&robot.known_map);
+        assert_eq!(islands.len(), 3);
+
+        // the paved tile reaches the tracker through the event, without reading the map again
+        let street = Tile { tile_type: TileType::Street, content: Content::None, elevation: 0 };
+        robot.handle_event(Event::Moved(street, (2, 3)));
+        assert_eq!(robot.island_tracker.borrow().map_revision(), Some(robot.known_map.revision()));
+        assert_eq!(robot.get_islands(&robot.known_map).len(), 2);
+        // the islands returned before the event don't change
+        assert_eq!(islands.len(), 3);
+
+        // the robot's island can be read while the tracker is borrowed
+        let targets = AsciiWorld::parse(include_str!("scenarios/targets.txt")).unwrap();
+        let robot = MinerRobot::new();
+        let islands = robot.get_islands(&targets.known_map());
+        let tracker = robot.island_tracker.borrow_mut();
+        assert!(robot.get_robot_island(&islands).map_or(false, |island| island.contains(&(0, 0))));
+        drop(tracker);
+    }
+    #[test]
+    fn test_island_tracker_joins_and_splits_only_the_changed_islands() {
+        let mut tracker = IslandTracker::new();
+        tracker.update(1, 5, vec![true, true, true, false, true]);
+        assert_eq!(tracker.islands().len(), 2);
+        assert_eq!(tracker.island_ids(), &vec![0, 1]);
+
+        // a paved tile joins the two islands, the older id is kept
+        tracker.set_walkable((0, 3), true);
+        assert!(tracker.is_dirty());
+        tracker.refresh();
+        assert_eq!(tracker.islands().len(), 1);
+        assert_eq!(tracker.island_ids(), &vec![0]);
+        assert!(tracker.same_island((0, 0), (0, 4)));
+
+        // a removed tile splits the island, the first part keeps the id
+        tracker.set_walkable((0, 1), false);
+        tracker.refresh();
+        assert_eq!(**tracker.islands(), vec![vec![(0, 0)], vec![(0, 2), (0, 3), (0, 4)]]);
+        assert_eq!(tracker.island_ids(), &vec![0, 2]);
+        assert_eq!(tracker.island_of((0, 3)), Some(1));
+
+        // a tile that comes and goes before the refresh doesn't join anything
+        tracker.set_walkable((0, 1), true);
+        tracker.set_walkable((0, 1), false);
+        tracker.refresh();
+        assert!(!tracker.same_island((0, 0), (0, 2)));
+        assert_eq!(tracker.island_ids(), &vec![0, 2]);
+
+        // the whole map only applies the tiles that differ
+        tracker.update(1, 5, vec![true, true, true, true, true]);
+        assert_eq!(tracker.island_ids(), &vec![0]);
+    }
+    #[test]
+    fn test_content_update_keeps_the_tile_known() {
+        let world = AsciiWorld::parse(include_str!("scenarios/archipelago.txt")).unwrap();
+        let mut robot = MinerRobot::new();
+        robot.known_map = world.known_map().with_policy(UnknownPolicy::Pessimistic).into();
+        robot.known_map_stale = false;
+
+        // the rocks of a walkable tile have been collected
+        let (row, col) = world.spawn;
+        let mut tile = world.map[row][col].clone();
+        tile.content = Content::None;
+        robot.handle_event(Event::TileContentUpdated(tile, (row, col)));
+
+        let map = robot.cached_known_map().unwrap();
+        assert!(!map.is_unknown(row as i32, col as i32));
+        assert!(robot.is_walkable_at(map, row as i32, col as i32));
+        assert_eq!(map.tile(row as i32, col as i32).map(|tile| tile.content.clone()), Some(Content::None));
+    }
+    #[test]
+    fn test_island_infos() {
+        let world = AsciiWorld::parse(include_str!("scenarios/targets.txt")).unwrap();
+        let robot = MinerRobot::new();
+        let mut known_map = world.known_map();
+        known_map.set((1, 4), KnownCell::Unknown);
+
+        let islands = robot.get_island_infos(&known_map);
+        assert_eq!(islands.len(), 3);
+        assert_eq!(robot.get_robot_island_info(&islands).map(|island| island.index), Some(0));
+
+        let islet = &islands[1];
+        assert_eq!((islet.area, islet.bounding_box, islet.centroid), (1, ((0, 4), (0, 4)), (0.0, 4.0)));
+        assert!(islet.touches_unexplored());
+
+        let continent = &islands[2];
+        assert_eq!(continent.area, 16);
+        assert_eq!(continent.bounding_box, ((5, 5), (8, 8)));
+        assert_eq!(continent.centroid, (6.5, 6.5));
+        assert!(continent.rocks >= 3);
+        assert_eq!(continent.content_count(&Content::Rock(0)), 3);
+        assert_eq!(continent.tile_types.get(&TileType::Grass), Some(&16));
+        // the tiles on the edge of the map are not on the coast
+        assert_eq!(continent.coastline.len(), 7);
+        assert!(!continent.touches_unexplored());
+
+        // the ids don't change when the islet joins the robot's island
+        let ids: Vec<usize> = islands.iter().map(|island| island.id).collect();
+        let mut map = world.map.clone();
+        map[0][3].tile_type = TileType::Street;
+        let islands = robot.get_island_infos(&KnownMap::from(map));
+        assert_eq!(islands.iter().map(|island| island.id).collect::<Vec<_>>(), vec![ids[0], ids[2]]);
+    }
+    #[test]
+    fn test_bridge_progress() {
+        let world = AsciiWorld::parse(include_str!("scenarios/archipelago.txt")).unwrap();
+        let network = MinerRobot::new().plan_bridge_network(&world.known_map());
+        let plan = network.schedule(1).into_iter().find(|plan| plan.target_island == 2).unwrap();
+
+        // one of the two shallow water tiles has been paved
+        let progress = BridgeProgress { plan, reached: 1 };
+        assert_eq!(progress.resume_tile(), progress.plan.path[1]);
+        assert_eq!(progress.remaining_tiles().len(), 1);
+        assert_eq!(progress.next_tile_rocks(), 2);
+        assert_eq!(progress.remaining_rocks(), 2);
+        assert!(progress.to_string().starts_with("1/2 tiles paved"));
+    }
+    #[test]
+    fn test_check_bridge() {
+        let world = AsciiWorld::parse(include_str!("scenarios/archipelago.txt")).unwrap();
+        let robot = MinerRobot::new();
+        let plan = robot.plan_bridge_network(&world.known_map()).schedule(1).into_iter().find(|plan| plan.target_island == 2).unwrap();
+
+        // nothing has been paved yet
+        match robot.check_bridge(&world.known_map(), &plan) {
+            Err(MinerError::BridgeVerificationFailed { unpaved, islands_merged }) => {
+                assert_eq!(unpaved, plan.tiles.iter().map(|tile| tile.coordinates).collect::<Vec<_>>());
+                assert!(!islands_merged);
+            }
+            other => panic!("unexpected result: {:?}", other)
+        }
+
+        let mut map = world.map.clone();
+        for tile in &plan.tiles {
+            map[tile.coordinates.0 as usize][tile.coordinates.1 as usize].tile_type = TileType::Street;
+        }
+        assert!(robot.check_bridge(&KnownMap::from(map), &plan).is_ok());
+    }
+    #[test]
+    fn test_target_strategies() {
+        let world = AsciiWorld::parse(include_str!("scenarios/targets.txt")).unwrap();
+        let robot = MinerRobot::new();
+        // the tile below the islet hasn't been discovered yet
+        let mut known_map = world.known_map();
+        known_map.set((1, 4), KnownCell::Unknown);
+
+        let islands = robot.get_island_infos(&known_map);
+        let candidates = robot.get_target_candidates(&known_map, &islands, 0);
+        assert_eq!(candidates.iter().map(|candidate| (candidate.island, candidate.bridge_rocks, candidate.area, candidate.frontier)).collect::<Vec<_>>(), vec![(1, 2, 1, 1), (2, 4, 16, 0)]);
+
+        let chosen = |strategy: TargetStrategy| strategy.selector().select(&candidates).map(|candidate| candidate.island);
+        assert_eq!(chosen(TargetStrategy::Closest), Some(1));
+        assert_eq!(chosen(TargetStrategy::MostFrontier), Some(1));
+        assert_eq!(chosen(TargetStrategy::LargestArea), Some(2));
+        assert_eq!(chosen(TargetStrategy::MostContent), Some(2));
+        assert_eq!(chosen(TargetStrategy::BestValue), Some(2));
+
+        // the bridge leads to the island chosen by the configured strategy
+        let config = MinerConfig::builder().target_strategy(TargetStrategy::MostContent).build().unwrap();
+        let plan = MinerRobot::with_config(config).plan_bridge(&world.known_map()).unwrap();
+        assert_eq!((plan.target_island, plan.total_rocks), (2, 4));
+        let plan = MinerRobot::new().plan_bridge(&world.known_map()).unwrap();
+        assert_eq!((plan.target_island, plan.total_rocks), (1, 2));
+    }
+    #[test]
+    fn test_locked_plan_stays_valid_until_the_islands_merge() {
+        let world = AsciiWorld::parse(include_str!("scenarios/targets.txt")).unwrap();
+        let robot = MinerRobot::new();
+        let plan = robot.plan_bridge(&world.known_map()).unwrap();
+        assert!(robot.is_plan_valid(&world.known_map(), &plan));
+
+        // a land connection to the islet has been discovered, so the bridge isn't needed anymore
+        let mut map = world.map.clone();
+        map[0][3].tile_type = TileType::Grass;
+        assert!(!robot.is_plan_valid(&KnownMap::from(map), &plan));
+    }
+    #[test]
+    fn test_lock_bridge_plan() {
+        let world = AsciiWorld::parse(include_str!("scenarios/targets.txt")).unwrap();
+        let map = world.known_map();
+        let rich_config = MinerConfig::builder().target_strategy(TargetStrategy::MostContent).build().unwrap();
+        let rich = MinerRobot::with_config(rich_config).plan_bridge(&map).unwrap();
+        assert_eq!(rich.total_rocks, 4);
+
+        // the bridge to the islet saves 2 rocks, which is not more than the default margin
+        let mut robot = MinerRobot::new();
+        robot.locked_bridge = Some(rich.clone());
+        assert_eq!(robot.lock_bridge_plan_on(&map).unwrap(), rich);
+        assert_eq!(robot.locked_bridge, Some(rich.clone()));
+
+        // with a smaller margin the cheaper bridge replaces the lock
+        let mut robot = MinerRobot::with_config(MinerConfig::builder().retarget_margin(1).build().unwrap());
+        robot.locked_bridge = Some(rich.clone());
+        let islet = robot.lock_bridge_plan_on(&map).unwrap();
+        assert_eq!((islet.target_island, islet.total_rocks), (1, 2));
+        assert_eq!(robot.locked_bridge, Some(islet.clone()));
+
+        // once the bridge to the islet is built the lock moves to the next bridge
+        let mut paved = world.map.clone();
+        for tile in &islet.tiles {
+            paved[tile.coordinates.0 as usize][tile.coordinates.1 as usize].tile_type = TileType::Street;
+        }
+        let next = robot.lock_bridge_plan_on(&KnownMap::from(paved)).unwrap();
+        assert_ne!(next, islet);
+        assert_eq!(next.total_rocks, 4);
+        assert_eq!(robot.locked_bridge, Some(next));
+    }
+    #[test]
+    fn test_unknown_policies() {
+        let world = AsciiWorld::parse("@GGGG\n").unwrap();
+        let robot = MinerRobot::new();
+        let mut known_map = world.known_map();
+        known_map.set((0, 2), KnownCell::Unknown);
+        assert_eq!(known_map.unknown_count(), 1);
+
+        // by default the unknown tile is deep water, so the bridge is planned across it
+        let islands = robot.get_islands(&known_map);
+        assert_eq!(islands.len(), 2);
+        let plan = robot.plan_bridge(&known_map).unwrap();
+        assert_eq!((plan.path.clone(), plan.total_rocks), (vec![(0, 1), (0, 2), (0, 3)], 3));
+
+        // with the optimistic policy the two sides are already the same island
+        let optimistic = known_map.clone().with_policy(UnknownPolicy::Optimistic);
+        assert_eq!(robot.get_islands(&optimistic).len(), 1);
+        assert!(robot.plan_bridge(&optimistic).is_none());
+
+        // with the excluded policy no bridge goes through the fog
+        let excluded = known_map.with_policy(UnknownPolicy::Excluded);
+        assert!(!robot.is_walkable_at(&excluded, 0, 2) && !robot.is_pavable_at(&excluded, 0, 2));
+        let islands = robot.get_islands(&excluded);
+        assert_eq!(islands.len(), 2);
+        assert!(robot.get_cheapest_bridge(&excluded, &islands[0], &islands[1]).is_none());
+
+        let config = MinerConfig::from_toml_str("unknown_policy = \"excluded\"\n").unwrap();
+        assert_eq!(config.unknown_policy, UnknownPolicy::Excluded);
+        assert_eq!(MinerConfig::default().unknown_policy, UnknownPolicy::Pessimistic);
+    }
+    #[test]
+    fn test_rock_budget_picks_the_cheapest_deposits() {
+        let deposit = |coordinates, rocks, path_cost| RockDeposit { coordinates, rocks, path_cost };
+        let config = MinerConfig::default();
+        let deposits = vec![deposit((0, 9), 5, 20), deposit((1, 1), 3, 2), deposit((2, 2), 2, 3), deposit((3, 3), 1, 1)];
+
+        // two small deposits close to the robot cost less than the big one far away
+        let budget = RockBudget::plan(6, 1, deposits.clone(), 10, &config);
+        assert_eq!(budget.deposits.iter().map(|deposit| deposit.coordinates).collect::<Vec<_>>(), vec![(1, 1), (2, 2)]);
+        assert_eq!(budget.shortfall, 0);
+        assert_eq!(budget.ticks_until_bridge, Some(6));
+        assert_eq!(budget.total_energy(), budget.harvest_energy + 10);
+
+        // the known deposits are not enough
+        let budget = RockBudget::plan(20, 1, deposits.clone(), 10, &config);
+        assert_eq!(budget.deposits.len(), 4);
+        assert_eq!(budget.shortfall, 8);
+        assert_eq!(budget.ticks_until_bridge, None);
+
+        // nothing to harvest
+        let budget = RockBudget::plan(3, 4, deposits.clone(), 10, &config);
+        assert!(budget.is_affordable());
+        assert!(budget.deposits.is_empty());
+        assert_eq!(budget.ticks_until_bridge, Some(2));
+
+        // the estimates come from the configuration
+        let slow = MinerConfig::builder().destroy_energy(10).ticks_per_deposit(5).ticks_per_bridge(3).build().unwrap();
+        let budget = RockBudget::plan(6, 1, deposits, 10, &slow);
+        assert_eq!(budget.ticks_until_bridge, Some(13));
+        assert_eq!(budget.harvest_energy, 2 + 3 + 2 * 10);
+    }
+    #[test]
+    fn test_scenario_paves_as_far_as_the_rocks_allow() {
+        let result = Scenario::from_ascii(include_str!("scenarios/partial_bridge.txt")).max_ticks(40).run();
+        result.assert_no_runner_error();
+        assert!((2..7).any(|row| result.is_paved((row, 3))));
+        assert!(!(2..7).any(|row| result.is_paved((row, 5))));
+    }
+    #[test]
+    fn test_cost_models() {
+        let world = AsciiWorld::parse("@WG\nDWD\nDWD\n").unwrap();
+
+        // by default walls are walkable, so there is a single island
+        let robot = MinerRobot::new();
+        assert_eq!(robot.get_islands(&world.known_map()).len(), 1);
+
+        // with the strict rules walls split the islands and cannot be paved
+        let robot = MinerRobot::new().with_cost_model(StrictCostModel);
+        let islands = robot.get_islands(&world.known_map());
+        assert_eq!(islands.len(), 2);
+        assert!(robot.get_cheapest_bridge(&world.known_map(), &islands[0], &islands[1]).is_none());
+        assert!(!StrictCostModel.is_pavable(&TileType::Wall));
+        assert_eq!(StrictCostModel.paving_cost(&TileType::ShallowWater), 2);
+        // the Lssf paths are checked with the robot's rules before the robot moves
+        let directions = [Direction::Right, Direction::Right];
+        assert_eq!(robot.first_forbidden_tile(&world.known_map(), (0, 0), &directions), Some((0, 1)));
+        assert_eq!(MinerRobot::new().first_forbidden_tile(&world.known_map(), (0, 0), &directions), None);
+        let lava = AsciiWorld::parse("@LG
+GGG
+").unwrap();
+        assert_eq!(MinerRobot::new().first_forbidden_tile(&lava.known_map(), (0, 0), &directions), Some((0, 1)));
+        let detour = [Direction::Down, Direction::Right, Direction::Right, Direction::Up];
+        assert_eq!(MinerRobot::new().first_forbidden_tile(&lava.known_map(), (0, 0), &detour), None);
+    }
+    #[test]
+    fn test_scenario_plays_paving_sounds() {
+        let sound = RecordingSound::new();
+        let result = Scenario::from_ascii(include_str!("scenarios/lava_gap.txt")).max_ticks(200).sound(sound.clone()).run();
+        result.assert_no_runner_error()
+            .assert_bridges_built_at_least(1);
+        assert!(sound.played(SoundEvent::MiningRock));
+        assert!(sound.played(SoundEvent::RockInLava));
+        assert!(!sound.played(SoundEvent::RockInWater));
+        assert_eq!(SoundEvent::RockInLava.name(), "rock_in_lava");
+    }
+    #[test]
+    fn test_handle_event_updates_state() {
+        let mut robot = MinerRobot::new();
+        let grass = Tile { tile_type: TileType::Grass, content: Content::None, elevation: 0 };
+
+        robot.handle_event(Event::Moved(grass.clone(), (3, 4)));
+        robot.handle_event(Event::Moved(grass, (3, 5)));
+        assert_eq!(robot.coordinates, (3, 5));
+        assert_eq!(robot.trail.iter().copied().collect::<Vec<_>>(), vec![(3, 4), (3, 5)]);
+        assert!(robot.visited.contains(&(3, 4)));
+
+        // only rocks are counted
+        robot.handle_event(Event::AddedToBackpack(Content::Rock(0), 4));
+        robot.handle_event(Event::AddedToBackpack(Content::Tree(0), 2));
+        robot.handle_event(Event::RemovedFromBackpack(Content::Rock(0), 3));
+        assert_eq!(robot.rocks_collected, 1);
+
+        robot.handle_event(Event::EnergyConsumed(10));
+        robot.handle_event(Event::EnergyConsumed(5));
+        robot.handle_event(Event::EnergyRecharged(3));
+        assert_eq!(robot.energy_ledger.consumed, 15);
+        assert_eq!(robot.energy_ledger.recharged, 3);
+        assert_eq!(robot.energy_ledger.balance(), -12);
+        assert_eq!(robot.energy_ledger.lowest_level, Some(robot.get_energy().get_energy_level()));
+    }
+    #[test]
+    fn test_instrumented_wrapper_collects_stats() {
+        let robot = Instrumented::new(MinerRobot::new().with_sound(SilentSound));
+        let stats = robot.stats_handle();
+        let mut generator = AsciiWorld::parse(include_str!("scenarios/two_islands.txt")).unwrap().into_generator().unwrap();
+        let mut runner = Runner::new(Box::new(robot), &mut generator).unwrap();
+
+        // the wrapper delegates to the robot
+        let coordinate = runner.get_robot().get_coordinate();
+        assert_eq!((coordinate.get_row(), coordinate.get_col()), (4, 1));
+
+        for _ in 0..5 {
+            runner.game_tick().unwrap();
+        }
+        let stats = stats.lock().unwrap().clone();
+        assert_eq!(stats.ticks, 5);
+        assert!(stats.max_tick_time >= stats.last_tick_time);
+        assert!(stats.mean_tick_time() <= stats.max_tick_time);
+    }
+    #[test]
+    fn test_config_builder_validation() {
+        let config = MinerConfig::builder().scan_distance(20).min_energy(150).build().unwrap();
+        assert_eq!(config.scan_distance, 20);
+        assert_eq!(config.min_energy, 150);
+
+        assert!(MinerConfig::builder().threshold(1.5).build().is_err());
+        assert!(MinerConfig::builder().scan_increase(0).build().is_err());
+    }
+    #[test]
+    fn test_config_from_toml_and_json() {
+        let config = MinerConfig::from_toml_str("goal_quantity = 8\ncollect_direction = \"left\"\n").unwrap();
+        assert_eq!(config.goal_quantity, 8);
+        assert_eq!(config.energy_budget, MinerConfig::default().energy_budget);
+
+        let config = MinerConfig::from_json_str("{\"scan_distance\": 15, \"threshold\": 0.3}").unwrap();
+        assert_eq!(config.scan_distance, 15);
+
+        assert!(MinerConfig::from_json_str("{\"min_energy\": 5000}").is_err());
+
+        let robot = MinerRobot::with_config(config);
+        assert_eq!(robot.scan_distance, 15);
+
+        // a configuration built by hand is checked before the robot is created
+        let config = MinerConfig { threshold: 2.0, ..MinerConfig::default() };
+        match MinerRobot::try_with_config(config) {
+            Err(error @ MinerError::Config(ConfigError::Invalid(_))) => assert_eq!(error.recovery(), Recovery::GiveUp),
+            other => panic!("unexpected result: {:?}", other.map(|robot| robot.scan_distance))
+        }
+        assert!(matches!(MinerRobot::from_config_file("missing.toml"), Err(MinerError::Config(ConfigError::Io(_)))));
+    }
+    #[test]
+    fn test_error_recovery_policy() {
+        assert_eq!(MinerError::from(LibError::NotEnoughEnergy).recovery(), Recovery::Recharge);
+        assert_eq!(MinerError::from(LibError::OutOfBounds).recovery(), Recovery::Skip);
+        assert_eq!(MinerError::NoTargetIsland.recovery(), Recovery::Replan);
+        assert_eq!(MinerError::ForbiddenPath { target: (0, 2), tile: (0, 1) }.recovery(), Recovery::Replan);
+        assert_eq!(MinerError::Config(ConfigError::Invalid(String::from("invalid"))).recovery(), Recovery::GiveUp);
+    }
+    #[test]
+    fn test_state_transitions_and_history() {
+        let config = MinerConfig::builder().state_history_size(2).build().unwrap();
+        let mut robot = MinerRobot::with_config(config);
+        assert_eq!(robot.state, RobotState::Exploring);
+
+        // collecting rocks requires the robot to travel to them first
+        assert!(robot.transition_to(RobotState::CollectingRocks).is_err());
+        robot.transition_to(RobotState::TravellingToTarget).unwrap();
+        robot.transition_to(RobotState::CollectingRocks).unwrap();
+        robot.transition_to(RobotState::Recharging).unwrap();
+
+        // the history only keeps the most recent transitions
+        let history: Vec<RobotState> = robot.state_history().map(|transition| transition.to.clone()).collect();
+        assert_eq!(history, vec![RobotState::CollectingRocks, RobotState::Recharging]);
+
+        robot.finish(FinishReason::NothingToCollect);
+        assert!(*robot.game_over.lock().unwrap());
+        assert!(robot.transition_to(RobotState::Exploring).is_err());
+    }
 }
 
 fn main() {